@@ -1,19 +1,29 @@
-use std::env::args;
+use clap::{Args, ValueEnum};
 
 const CAPITAL: char = '√';
 const REPEAT: char = 'Ω';
 
-fn main() {
-    let mut args = args().skip(1);
-    let mode = args.next().expect("A mode must be provided");
-    let msg = args.next().expect("A message must be provided");
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CipherMode {
+    Encode,
+    Decode,
+}
+
+#[derive(Debug, Args)]
+pub struct CipherArgs {
+    /// Whether to encode or decode the message
+    #[arg(value_enum)]
+    pub mode: CipherMode,
+    /// The message to encode or decode
+    pub message: String,
+}
 
+pub fn cipher(args: &CipherArgs) {
     println!(
         "{}",
-        match mode.as_str() {
-            "encode" => encode(&msg),
-            "decode" => decode(&msg),
-            _ => String::from("Invalid mode"),
+        match args.mode {
+            CipherMode::Encode => encode(&args.message),
+            CipherMode::Decode => decode(&args.message),
         }
     );
 }
@@ -0,0 +1,85 @@
+use std::{
+    collections::HashMap,
+    fs::{read_to_string, File},
+    io::Write,
+    path::Path,
+};
+
+use common::{
+    config::Config,
+    name::BatchNames,
+    pipeline::{rules_version, ProcessedYaml},
+    util::stable_hash,
+    warning::Warning,
+};
+use serde::{Deserialize, Serialize};
+
+// results of processing a slot, reused as long as nothing that went into them changed
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cache {
+    // the batch seed of the run that wrote the cache, reused when no seed is passed
+    #[serde(default)]
+    seed: Option<u64>,
+    entries: HashMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    key: u64,
+    pub processed: ProcessedYaml,
+    pub warnings: Vec<Warning>,
+}
+
+impl Cache {
+    // a missing or unreadable cache just means everything is processed again
+    pub fn load(path: &Path) -> Self {
+        read_to_string(path).ok().and_then(|content| serde_json::from_str(&content).ok()).unwrap_or_default()
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+    }
+
+    pub fn get(&self, id: &str, key: u64) -> Option<&CacheEntry> {
+        self.entries.get(id).filter(|entry| entry.key == key)
+    }
+
+    pub fn insert(&mut self, id: &str, key: u64, processed: ProcessedYaml, warnings: Vec<Warning>) {
+        self.entries.insert(id.to_string(), CacheEntry { key, processed, warnings });
+    }
+
+    // only keeps the rows of the current process list, so the cache doesn't grow with every bucket file ever processed
+    pub fn retain(&mut self, ids: &[&str]) {
+        self.entries.retain(|id, _| ids.contains(&id.as_str()));
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let content = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        File::create(path)?.write_all(content.as_bytes())
+    }
+}
+
+// everything that decides how a slot is processed, besides the slot's bucket id which the cache is indexed by
+pub fn cache_key(name: &str, content: &str, new_names: &[Option<String>], seed: u64, config: &Config, batch_names: &BatchNames) -> u64 {
+    let mut inputs = vec![
+        name.to_string(),
+        stable_hash(content.as_bytes()).to_string(),
+        format!("{new_names:?}"),
+        seed.to_string(),
+        rules_version().to_string(),
+        format!("{:?}", config.names.policy),
+        format!("{:?}", config.games),
+        format!("{:?}", config.points.modifiers),
+    ];
+
+    // plandos and item links are resolved against the rest of the batch, so those slots also depend on every other name
+    if content.contains("plando") || content.contains("item_links") {
+        inputs.push(batch_names.fingerprint().to_string());
+    }
+
+    stable_hash(inputs.join("\n").as_bytes())
+}
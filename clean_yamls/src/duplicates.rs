@@ -0,0 +1,73 @@
+use std::{fs::read_to_string, path::Path};
+
+use common::{
+    config::Paths,
+    fingerprint::fingerprint,
+    warning::{WarningKind, Warnings},
+};
+
+use crate::ingest::bucket_id;
+
+// fingerprints of every submission in the bucket and used directories, to catch the same yaml being submitted twice
+pub struct Fingerprints {
+    bucket: Vec<(u32, u64)>,
+    used: Vec<(u32, u64)>,
+}
+
+impl Fingerprints {
+    pub fn load(paths: &Paths) -> Self {
+        Fingerprints {
+            bucket: fingerprint_dir(&paths.bucket),
+            used: fingerprint_dir(&paths.used),
+        }
+    }
+
+    pub fn add_to_bucket(&mut self, id: u32, fingerprint: u64) {
+        self.bucket.push((id, fingerprint));
+    }
+
+    // describes every other submission with the same content as the given one
+    pub fn duplicates_of(&self, id: u32, fingerprint: u64) -> Vec<String> {
+        let in_bucket = self
+            .bucket
+            .iter()
+            .filter(|(other, other_fingerprint)| *other != id && *other_fingerprint == fingerprint)
+            .map(|(other, _)| format!("is the same submission as bucket id {other}"));
+        let in_used = self
+            .used
+            .iter()
+            .filter(|(other, other_fingerprint)| *other != id && *other_fingerprint == fingerprint)
+            .map(|(other, _)| format!("is the same submission as bucket id {other}, which has already been used"));
+
+        in_bucket.chain(in_used).collect()
+    }
+
+    pub fn check_slot(&self, id: &str, warnings: &mut Warnings) {
+        let Ok(id) = id.parse() else {
+            return;
+        };
+
+        if let Some((_, fingerprint)) = self.bucket.iter().find(|(other, _)| *other == id) {
+            for duplicate in self.duplicates_of(id, *fingerprint) {
+                warnings.push(WarningKind::DuplicateSubmission, None, duplicate);
+            }
+        }
+    }
+}
+
+fn fingerprint_dir(dir: &Path) -> Vec<(u32, u64)> {
+    let Ok(dir) = dir.read_dir() else {
+        return vec![];
+    };
+
+    let mut fingerprints: Vec<_> = dir
+        .flatten()
+        .filter_map(|entry| {
+            let id = bucket_id(&entry.file_name().to_string_lossy())?;
+            let fingerprint = fingerprint(&read_to_string(entry.path()).ok()?)?;
+            Some((id, fingerprint))
+        })
+        .collect();
+    fingerprints.sort();
+    fingerprints
+}
@@ -1,73 +0,0 @@
-use common::valid_games::VALID_GAMES;
-use hashlink::LinkedHashMap;
-use phf::phf_map;
-use rand::thread_rng;
-use rand_distr::{Distribution, WeightedIndex};
-use yaml_rust2::Yaml;
-
-const GAME_RENAMES: phf::Map<&'static str, &'static str> = phf_map!(
-    "PokéPark Wii: Pikachu's Adventure [JP]" => "PokePark",
-    "PokéPark" => "PokePark",
-    "Paper Mario The Thousand Year Door" => "Paper Mario: The Thousand-Year Door",
-    "Metroid Zero Mission" => "Metroid: Zero Mission",
-    "Plants vs. Zombies: Replanted" => "Plants vs. Zombies",
-    "The Simpsons Hit And Run" => "Simpsons Hit and Run"
-);
-
-pub fn choose_game(doc: &mut Yaml) -> Option<Yaml> {
-    let game_key = Yaml::from_str("game");
-
-    if let Some(hash) = doc.as_mut_hash() {
-        for (old, new) in GAME_RENAMES.entries() {
-            rename_game(hash, old, new);
-        }
-    }
-
-    if let Some(games) = doc.as_mut_hash()?.get_mut(&game_key) {
-        let game = match games {
-            Yaml::Hash(games) => {
-                let mut rng = thread_rng();
-
-                let mut games: Vec<_> = games
-                    .iter()
-                    .filter_map(|(k, v)| match (k.as_str(), v.as_f64(), v.as_i64()) {
-                        (Some(game), Some(weight), None) => Some((game, weight as i64)),
-                        (Some(game), None, Some(weight)) => Some((game, weight)),
-                        _ => None,
-                    })
-                    .collect();
-
-                for (game, _) in &mut games {
-                    if let Some(new_name) = GAME_RENAMES.get(game) {
-                        *game = *new_name;
-                    }
-                }
-
-                if games.iter().any(|(game, weight)| *weight > 0 && VALID_GAMES.contains(game)) {
-                    games.retain(|(game, _)| VALID_GAMES.contains(game));
-                }
-
-                let dist = WeightedIndex::new(games.iter().map(|(_, weight)| weight)).expect("Failed to create index");
-                Yaml::from_str(games[dist.sample(&mut rng)].0)
-            }
-            Yaml::String(game) => {
-                if let Some(new_name) = GAME_RENAMES.get(game) {
-                    *game = String::from(*new_name);
-                }
-                Yaml::from_str(game)
-            }
-            _ => return None,
-        };
-
-        *games = game.clone();
-        Some(game)
-    } else {
-        None
-    }
-}
-
-fn rename_game(hash: &mut LinkedHashMap<Yaml, Yaml>, from: &str, to: &str) {
-    if let Some(options) = hash.remove(&Yaml::from_str(from)) {
-        hash.insert(Yaml::from_str(to), options);
-    }
-}
@@ -0,0 +1,137 @@
+use std::{
+    fs::{read, remove_file, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+use common::{
+    args::ConfigArgs,
+    config::{Config, Paths},
+    fingerprint::fingerprint,
+};
+use yaml_rust2::YamlLoader;
+
+use crate::duplicates::Fingerprints;
+
+const INGEST_EXTENSIONS: [&str; 3] = ["yaml", "yml", "txt"];
+
+pub fn bucket_id(file_name: &str) -> Option<u32> {
+    file_name.strip_prefix("bucket (")?.strip_suffix(").yaml")?.parse().ok()
+}
+
+fn next_free_id(paths: &Paths) -> u32 {
+    [&paths.bucket, &paths.used]
+        .iter()
+        .filter_map(|dir| dir.read_dir().ok())
+        .flat_map(|dir| dir.flatten())
+        .filter_map(|entry| bucket_id(&entry.file_name().to_string_lossy()))
+        .max()
+        .map_or(1, |id| id + 1)
+}
+
+#[derive(Debug, Args)]
+pub struct IngestArgs {
+    #[command(flatten)]
+    pub config: ConfigArgs,
+    /// Directory to take new submissions from instead of the bucket
+    #[arg(long)]
+    pub from: Option<PathBuf>,
+}
+
+// renames new submissions to the next free bucket ids, refusing anything that isn't a valid yaml
+pub fn ingest(args: &IngestArgs) {
+    let config = Config::load(&args.config);
+    let paths = &config.paths;
+    let source = args.from.clone().unwrap_or_else(|| paths.bucket.clone());
+
+    let Ok(dir) = source.read_dir() else {
+        println!("Failed to read '{}'", source.display());
+        return;
+    };
+
+    let mut files: Vec<_> = dir
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| INGEST_EXTENSIONS.contains(&extension.to_string_lossy().to_lowercase().as_str()))
+        })
+        .filter(|path| path.file_name().is_none_or(|file_name| bucket_id(&file_name.to_string_lossy()).is_none()))
+        .collect();
+    files.sort();
+
+    let mut id = next_free_id(paths);
+    let mut fingerprints = Fingerprints::load(paths);
+    let mut ingested = 0;
+
+    for path in files {
+        let original = path.file_name().map(|file_name| file_name.to_string_lossy().to_string()).unwrap_or_default();
+
+        let content = match read(&path).map(String::from_utf8) {
+            Ok(Ok(content)) => normalize(&content),
+            Ok(Err(_)) => {
+                println!("Refusing '{original}': not valid UTF-8");
+                continue;
+            }
+            Err(err) => {
+                println!("Refusing '{original}': {err}");
+                continue;
+            }
+        };
+
+        match YamlLoader::load_from_str(&content) {
+            Ok(documents) if !documents.is_empty() => (),
+            Ok(_) => {
+                println!("Refusing '{original}': contains no yaml documents");
+                continue;
+            }
+            Err(err) => {
+                println!("Refusing '{original}': {err}");
+                continue;
+            }
+        }
+
+        let target = paths.bucket.join(format!("bucket ({id}).yaml"));
+        if let Err(err) = write_new(&target, &content) {
+            println!("Failed to write '{}': {err}", target.display());
+            continue;
+        }
+
+        if let Err(err) = remove_file(&path) {
+            println!("Ingested '{original}' as bucket id {id}, but failed to remove the original: {err}");
+        } else {
+            println!("Ingested '{original}' as bucket id {id}");
+        }
+
+        if let Err(err) = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&paths.ingest_index)
+            .and_then(|mut index| writeln!(index, "{original}\t{id}"))
+        {
+            println!("Failed to write to '{}': {err}", paths.ingest_index.display());
+        }
+
+        if let Some(fingerprint) = fingerprint(&content) {
+            for duplicate in fingerprints.duplicates_of(id, fingerprint) {
+                println!("  Warning: bucket id {id} {duplicate}");
+            }
+            fingerprints.add_to_bucket(id, fingerprint);
+        }
+
+        id += 1;
+        ingested += 1;
+    }
+
+    println!("Ingested {ingested} file(s)");
+}
+
+fn normalize(content: &str) -> String {
+    content.trim_start_matches('\u{feff}').replace("\r\n", "\n").replace('\r', "\n")
+}
+
+fn write_new(path: &Path, content: &str) -> std::io::Result<()> {
+    File::create_new(path)?.write_all(content.as_bytes())
+}
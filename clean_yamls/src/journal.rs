@@ -0,0 +1,146 @@
+use std::{
+    fs::{read_to_string, rename, File, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use clap::Args;
+use common::{
+    args::ConfigArgs,
+    config::{Config, Paths},
+};
+use rand::{thread_rng, Rng};
+
+pub struct JournalEntry {
+    pub run_id: String,
+    pub id: String,
+    pub name: String,
+    pub timestamp: u64,
+}
+
+pub fn timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0)
+}
+
+// the random suffix keeps runs started within the same second apart
+pub fn new_run_id() -> String {
+    format!("{}-{:04x}", timestamp(), thread_rng().gen::<u16>())
+}
+
+pub fn read_journal(path: &Path) -> Vec<JournalEntry> {
+    let Ok(content) = read_to_string(path) else {
+        return vec![];
+    };
+
+    content
+        .lines()
+        .zip(1..)
+        .filter(|(line, _)| !line.is_empty())
+        .filter_map(|(line, i)| match line.split('\t').collect::<Vec<_>>().as_slice() {
+            [run_id, id, name, timestamp] => Some(JournalEntry {
+                run_id: run_id.to_string(),
+                id: id.to_string(),
+                name: name.to_string(),
+                timestamp: timestamp.parse().unwrap_or(0),
+            }),
+            _ => {
+                println!("Skipping malformed line {i} in '{}'", path.display());
+                None
+            }
+        })
+        .collect()
+}
+
+pub enum MoveError {
+    // the file is still in the bucket
+    Move(std::io::Error),
+    // the file was moved, but `restore` doesn't know about it
+    Journal(std::io::Error),
+}
+
+// moves a processed bucket file to the used directory and records the move, so it can be undone with `restore`
+pub fn move_to_used(paths: &Paths, run_id: &str, id: &str, name: &str) -> Result<(), MoveError> {
+    let file_name = format!("bucket ({id}).yaml");
+    rename(paths.bucket.join(&file_name), paths.used.join(&file_name)).map_err(MoveError::Move)?;
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&paths.journal)
+        .and_then(|mut journal| writeln!(journal, "{run_id}\t{id}\t{name}\t{}", timestamp()))
+        .map_err(MoveError::Journal)
+}
+
+#[derive(Debug, Args)]
+pub struct RestoreArgs {
+    #[command(flatten)]
+    pub config: ConfigArgs,
+    /// Restore every file moved in this run
+    #[arg(long = "run")]
+    pub run_id: Option<String>,
+    /// Restore the file with this bucket id, can be repeated
+    #[arg(long = "id")]
+    pub ids: Vec<String>,
+}
+
+pub fn restore(args: &RestoreArgs) {
+    let config = Config::load(&args.config);
+    let paths = &config.paths;
+    let run_id = &args.run_id;
+    let ids = &args.ids;
+    let entries = read_journal(&paths.journal);
+
+    if run_id.is_none() && ids.is_empty() {
+        let mut runs: Vec<(&str, usize, u64)> = vec![];
+        for entry in &entries {
+            if let Some((_, count, _)) = runs.iter_mut().find(|(run_id, _, _)| *run_id == entry.run_id) {
+                *count += 1;
+            } else {
+                runs.push((&entry.run_id, 1, entry.timestamp));
+            }
+        }
+
+        if runs.is_empty() {
+            println!("No moves recorded in '{}'", paths.journal.display());
+        } else {
+            println!("Pass --run <run id> and/or --id <bucket id> to choose what to restore. Recorded runs:");
+            for (run_id, count, timestamp) in runs {
+                println!("  {run_id}: {count} file(s), moved at {timestamp}");
+            }
+        }
+        return;
+    }
+
+    let mut kept = vec![];
+    let mut restored = 0;
+
+    for entry in entries {
+        let selected = run_id.as_ref().is_none_or(|run_id| *run_id == entry.run_id) && (ids.is_empty() || ids.contains(&entry.id));
+
+        if !selected {
+            kept.push(entry);
+            continue;
+        }
+
+        let file_name = format!("bucket ({}).yaml", entry.id);
+        let target = paths.bucket.join(&file_name);
+        if target.exists() {
+            println!("Not restoring '{file_name}' ({}), it already exists in the bucket", entry.name);
+            kept.push(entry);
+        } else if let Err(err) = rename(paths.used.join(&file_name), target) {
+            println!("Failed to restore '{file_name}' ({}): {err}", entry.name);
+            kept.push(entry);
+        } else {
+            println!("Restored '{file_name}' ({})", entry.name);
+            restored += 1;
+        }
+    }
+
+    let content: String = kept.iter().map(|entry| format!("{}\t{}\t{}\t{}\n", entry.run_id, entry.id, entry.name, entry.timestamp)).collect();
+    if let Err(err) = File::create(&paths.journal).and_then(|mut writer| writer.write_all(content.as_bytes())) {
+        println!("Failed to update '{}': {err}", paths.journal.display());
+    }
+
+    println!("Restored {restored} file(s)");
+}
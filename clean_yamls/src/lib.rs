@@ -0,0 +1,288 @@
+mod cache;
+mod duplicates;
+mod ingest;
+mod journal;
+mod read;
+mod watch;
+
+use std::{
+    fmt::Write as FmtWrite,
+    fs::{read_to_string, File},
+    io::Write,
+    path::Path,
+    process::exit,
+};
+
+use clap::Args;
+use common::{
+    args::ConfigArgs,
+    config::Config,
+    name::{BatchNames, NameRules},
+    name_changes::load_name_mapping,
+    pipeline::{assign_names, process_yaml, slot_name, BatchOutput, OutputWriters, PipelineOptions, ProcessedYaml},
+    util::{derive_seed, random_seed, slot_rng},
+    warning::{print_warnings, WarningKind, Warnings},
+};
+
+use crate::{
+    cache::{cache_key, Cache},
+    duplicates::Fingerprints,
+    journal::{move_to_used, new_run_id, MoveError},
+    read::read_process_list,
+    watch::watch,
+};
+pub use crate::{
+    ingest::{ingest, IngestArgs},
+    journal::{restore, RestoreArgs},
+};
+
+#[derive(Debug, Args)]
+pub struct CleanArgs {
+    #[command(flatten)]
+    pub config: ConfigArgs,
+    /// Batch seed to reuse, a random one is chosen otherwise
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Process everything without writing or moving any files
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Move processed bucket files to the used directory
+    #[arg(long)]
+    pub move_files: bool,
+    /// Process every slot again, even if it is unchanged since the last run
+    #[arg(long)]
+    pub no_cache: bool,
+    /// Keep running and process the batch again whenever the bucket or process list changes
+    #[arg(long, conflicts_with = "move_files")]
+    pub watch: bool,
+}
+
+pub fn clean(args: &CleanArgs) {
+    let config = Config::load(&args.config);
+    let paths = &config.paths;
+
+    let mut cache = if args.no_cache { Cache::default() } else { Cache::load(&paths.cache) };
+
+    // the batch seed of the last run is reused and kept for the whole watch, so unchanged slots keep their rolls and their cached results
+    let batch_seed = match (args.seed, cache.seed()) {
+        (Some(seed), _) => {
+            println!("Using seed {seed}");
+            seed
+        }
+        (None, Some(seed)) => {
+            println!("Using seed {seed} from '{}'", paths.cache.display());
+            seed
+        }
+        (None, None) => {
+            let seed = random_seed();
+            println!("Using seed {seed}");
+            seed
+        }
+    };
+    cache.set_seed(batch_seed);
+
+    if args.watch {
+        watch(args, &config, batch_seed, &mut cache);
+    } else if !clean_batch(args, &config, batch_seed, &mut cache, false) {
+        exit(1);
+    }
+}
+
+// processes every slot in the process list, returns false if the process list couldn't be used
+//
+// `fresh_only` only prints the warnings of slots that were reprocessed, for watch runs where the rest has been printed before
+pub(crate) fn clean_batch(args: &CleanArgs, config: &Config, batch_seed: u64, cache: &mut Cache, fresh_only: bool) -> bool {
+    let paths = &config.paths;
+
+    let process_list = match read_process_list(&paths.process_list, &paths.bucket, &paths.used) {
+        Ok(process_list) => process_list,
+        Err(problems) => {
+            println!("'{}' has {} problem(s), nothing has been processed:", paths.process_list.display(), problems.len());
+            for problem in problems {
+                println!("  {problem}");
+            }
+            return false;
+        }
+    };
+
+    let dry_run = args.dry_run;
+    let move_files = args.move_files;
+    let run_id = new_run_id();
+
+    // output files are created up front so a bad working directory fails before any processing, but only filled in at the end
+    let writers = if dry_run { None } else { Some(OutputWriters::create(paths)) };
+
+    let mut batch_output = BatchOutput::new(paths);
+    let mut dry_run_report = String::new();
+
+    let item_location_mappings = load_name_mapping();
+    let mut name_rules = NameRules::new(config.names.policy);
+    let mut batch_names = BatchNames::default();
+    let fingerprints = Fingerprints::load(paths);
+    let mut reprocessed = vec![];
+    let mut reused = 0;
+
+    // names are assigned for the whole batch first, so plandos can target slots that are processed later
+    let slots: Vec<_> = process_list
+        .into_iter()
+        .map(|(name, id, seed)| {
+            let mut warnings = Warnings::new(&name, Some(&id));
+            fingerprints.check_slot(&id, &mut warnings);
+            let content = read_bucket_file(&paths.bucket, &id);
+            let new_names = content
+                .as_ref()
+                .map(|content| assign_names(&mut name_rules, &mut batch_names, content, &mut warnings))
+                .unwrap_or_default();
+            (name, id, seed, warnings, content, new_names)
+        })
+        .collect();
+
+    let options = PipelineOptions {
+        mappings: &item_location_mappings,
+        batch_names: &batch_names,
+        games: &config.games,
+        points: &config.points,
+    };
+
+    let ids: Vec<_> = slots.iter().map(|(_, id, ..)| id.clone()).collect();
+
+    for (name, id, seed, mut warnings, content, new_names) in slots {
+        let seed = seed.unwrap_or_else(|| derive_seed(batch_seed, &id));
+        let mut rng = slot_rng(seed);
+        let source = format!("bucket ({id}).yaml");
+        let processed = match content {
+            Ok(content) => {
+                let key = cache_key(&name, &content, &new_names, seed, config, &batch_names);
+                if let Some(entry) = cache.get(&id, key) {
+                    reused += 1;
+                    warnings.extend(entry.warnings.clone());
+                    entry.processed.clone()
+                } else {
+                    // warnings from processing are cached with the result, the ones about the rest of the batch are checked every run
+                    let mut processing_warnings = Warnings::new(&name, Some(&id));
+                    let processed = process_yaml(&content, &source, &new_names, &options, &mut processing_warnings, &mut rng);
+                    let processing_warnings = processing_warnings.into_vec();
+                    cache.insert(&id, key, processed.clone(), processing_warnings.clone());
+                    warnings.extend(processing_warnings);
+                    reprocessed.push(name.clone());
+                    processed
+                }
+            }
+            Err(err) => {
+                warnings.push(WarningKind::ReadFailed, None, format!("error when reading '{source}': {err}"));
+                ProcessedYaml::failed()
+            }
+        };
+
+        // the dist file and every output use the name the yaml is written with, which isn't the listed one if it was shortened
+        let name = slot_name(&name, &new_names);
+        let dist_path = paths.dist.join(format!("{name}.yaml"));
+
+        if !dry_run {
+            let mut written = false;
+            if let Some(output) = &processed.text {
+                match File::create(&dist_path) {
+                    Ok(mut writer) => {
+                        if let Err(err) = writer.write_all(output.as_bytes()) {
+                            warnings.push(WarningKind::WriteFailed, None, format!("error when writing to '{name}.yaml': {err}"));
+                        } else {
+                            written = true;
+                        }
+                    }
+                    Err(err) => warnings.push(WarningKind::WriteFailed, None, format!("error when creating '{name}.yaml': {err}")),
+                };
+            }
+
+            // files that failed to process stay in the bucket so they can be fixed and rerun
+            if move_files && written {
+                match move_to_used(paths, &run_id, &id, &name) {
+                    Ok(()) => {}
+                    Err(MoveError::Move(err)) => warnings.push(WarningKind::WriteFailed, None, format!("failed to move 'bucket ({id}).yaml' to used directory: {err}")),
+                    Err(MoveError::Journal(err)) => warnings.push(
+                        WarningKind::WriteFailed,
+                        None,
+                        format!("moved 'bucket ({id}).yaml' to used directory, but failed to record it in '{}': {err}", paths.journal.display()),
+                    ),
+                }
+            }
+        }
+
+        // added last, so the bot output carries every warning of the slot
+        let points = batch_output.add_slot(&name, Some(&id), seed, &processed, &config.points, warnings);
+
+        if dry_run {
+            let _ = writeln!(dry_run_report, "'{name}' (bucket {id}, seed {seed})");
+            let _ = writeln!(
+                dry_run_report,
+                "  games: {}",
+                processed.games.iter().map(|(game, count, _)| format!("{game} x{count}")).collect::<Vec<_>>().join(", ")
+            );
+            match &points {
+                Some(points) => {
+                    let _ = writeln!(dry_run_report, "  points: {}", points.describe());
+                }
+                None => {
+                    let _ = writeln!(dry_run_report, "  rejected");
+                }
+            }
+            for (_, roll) in &processed.rolls {
+                let odds: Vec<_> = roll.probabilities().iter().map(|(game, probability)| format!("{game} {:.1}%", probability * 100.0)).collect();
+                let _ = writeln!(dry_run_report, "  rolled {} from {}", roll.winner, odds.join(", "));
+                let removed: Vec<_> = roll.removed().map(|(game, reason)| format!("{game} ({reason})")).collect();
+                if !removed.is_empty() {
+                    let _ = writeln!(dry_run_report, "  removed from the roll: {}", removed.join(", "));
+                }
+            }
+            if let Some(output) = &processed.text {
+                let _ = writeln!(dry_run_report, "  would write {} ({} lines)", dist_path.display(), output.lines().count());
+            }
+            if move_files && processed.text.is_some() {
+                let file_name = format!("bucket ({id}).yaml");
+                let _ = writeln!(dry_run_report, "  would move {} to {}", paths.bucket.join(&file_name).display(), paths.used.join(&file_name).display());
+            }
+        }
+    }
+
+    if fresh_only {
+        let fresh: Vec<_> = batch_output.warnings.iter().filter(|warning| reprocessed.contains(&warning.slot)).cloned().collect();
+        print_warnings(&fresh);
+    } else {
+        print_warnings(&batch_output.warnings);
+    }
+
+    println!("\n{}", batch_output.summary());
+
+    if reprocessed.is_empty() {
+        println!("No slot was reprocessed");
+    } else {
+        println!("Reprocessed {} slot(s): {}", reprocessed.len(), reprocessed.join(", "));
+    }
+    if reused > 0 {
+        println!("Reused {reused} unchanged slot(s) from '{}'", paths.cache.display());
+    }
+
+    if move_files && !dry_run {
+        println!("Moved files are recorded under run {run_id} in '{}'", paths.journal.display());
+    }
+
+    if let Some(writers) = writers {
+        cache.retain(&ids.iter().map(String::as_str).collect::<Vec<_>>());
+        if let Err(err) = cache.save(&paths.cache) {
+            println!("Failed to write to cache file: {err}");
+        }
+        batch_output.write(paths, writers);
+    } else {
+        println!("\nDry run, nothing has been written or moved.\n");
+        print!("{dry_run_report}");
+        println!("\n{} would contain:", paths.output_list.display());
+        print!("{}", String::from_utf8_lossy(batch_output.output_list()));
+        println!("\n{} would contain:", paths.output_bot.display());
+        print!("{}", String::from_utf8_lossy(batch_output.bot_output()));
+    }
+
+    true
+}
+
+fn read_bucket_file(bucket_path: &Path, id: &str) -> std::io::Result<String> {
+    read_to_string(bucket_path.join(format!("bucket ({id}).yaml")))
+}
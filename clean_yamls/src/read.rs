@@ -1,31 +1,98 @@
 use std::{
+    collections::HashMap,
     fs::File,
     io::{BufRead, BufReader},
     path::Path,
 };
 
-use crate::PROCESS_LIST_PATH;
-
-pub fn read_process_list() -> Vec<(String, String)> {
-    BufReader::new(
-        File::open(Path::new(PROCESS_LIST_PATH))
-            .unwrap_or_else(|_| panic!("Failed to open '{PROCESS_LIST_PATH}'")),
-    )
-    .lines()
-    .zip(1..)
-    .map(|(line, i)| {
-        (
-            line.unwrap_or_else(|_| panic!("Failed to read line {i}.")),
-            i,
-        )
-    })
-    .filter(|(line, _)| !line.is_empty())
-    .map(|(line, i)| {
-        if let Some((name, id)) = line.split_once('\t') {
-            (name.to_string(), id.to_string())
+pub type ProcessEntry = (String, String, Option<u64>);
+
+// reads and validates the whole process list up front, returning every problem found rather than stopping at the first
+pub fn read_process_list(path: &Path, bucket_path: &Path, used_path: &Path) -> Result<Vec<ProcessEntry>, Vec<String>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => return Err(vec![format!("Failed to open '{}': {err}", path.display())]),
+    };
+
+    let mut entries = vec![];
+    let mut problems = vec![];
+    let mut names: HashMap<String, usize> = HashMap::new();
+    let mut ids: HashMap<String, usize> = HashMap::new();
+
+    for (line, i) in BufReader::new(file).lines().zip(1..) {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                problems.push(format!("Line {i}: failed to read line: {err}"));
+                continue;
+            }
+        };
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.trim_end() != line {
+            problems.push(format!("Line {i}: has trailing whitespace"));
+        }
+
+        let columns: Vec<_> = line.trim_end().split('\t').collect();
+
+        let (name, id, seed) = match columns.as_slice() {
+            [name, id] => (*name, *id, None),
+            [name, id, seed] => match seed.parse() {
+                Ok(seed) => (*name, *id, Some(seed)),
+                Err(_) => {
+                    problems.push(format!("Line {i}: has an invalid seed '{seed}'"));
+                    (*name, *id, None)
+                }
+            },
+            [_] => {
+                problems.push(format!("Line {i}: does not contain a pair of name and id"));
+                continue;
+            }
+            [name, id, ..] => {
+                problems.push(format!("Line {i}: has {} columns, expected name, id and an optional seed", columns.len()));
+                (*name, *id, None)
+            }
+            [] => continue,
+        };
+
+        if name.trim() != name || id.trim() != id {
+            problems.push(format!("Line {i}: has whitespace around the name or id"));
+        }
+
+        if name.is_empty() {
+            problems.push(format!("Line {i}: has an empty name"));
+        } else if let Some(first) = names.get(name) {
+            problems.push(format!("Line {i}: name '{name}' is already used on line {first}"));
         } else {
-            panic!("Line {i} does not contain a pair of name and id.");
+            names.insert(name.to_string(), i);
         }
-    })
-    .collect()
+
+        if id.is_empty() {
+            problems.push(format!("Line {i}: has an empty id"));
+        } else {
+            if let Some(first) = ids.get(id) {
+                problems.push(format!("Line {i}: bucket id {id} is already used on line {first}"));
+            } else {
+                ids.insert(id.to_string(), i);
+            }
+
+            let file_name = format!("bucket ({id}).yaml");
+            if used_path.join(&file_name).exists() {
+                problems.push(format!("Line {i}: '{file_name}' has already been used"));
+            } else if !bucket_path.join(&file_name).exists() {
+                problems.push(format!("Line {i}: '{file_name}' does not exist in the bucket"));
+            }
+        }
+
+        entries.push((name.to_string(), id.to_string(), seed));
+    }
+
+    if problems.is_empty() {
+        Ok(entries)
+    } else {
+        Err(problems)
+    }
 }
@@ -0,0 +1,64 @@
+use std::{collections::BTreeMap, fs::metadata, path::PathBuf, thread::sleep, time::Duration, time::SystemTime};
+
+use common::config::Config;
+
+use crate::{cache::Cache, clean_batch, CleanArgs};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// modification time and size of every file that can change the batch
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+// processes the batch, then polls the bucket and process list and processes it again on every change, until interrupted
+pub fn watch(args: &CleanArgs, config: &Config, batch_seed: u64, cache: &mut Cache) {
+    let mut snapshot = take_snapshot(config);
+    clean_batch(args, config, batch_seed, cache, false);
+
+    loop {
+        println!(
+            "\nWatching '{}' and '{}' for changes, press Ctrl+C to stop",
+            config.paths.bucket.display(),
+            config.paths.process_list.display()
+        );
+
+        let changed = loop {
+            sleep(POLL_INTERVAL);
+            let new_snapshot = take_snapshot(config);
+            let changed = changed_files(&snapshot, &new_snapshot);
+            if !changed.is_empty() {
+                snapshot = new_snapshot;
+                break changed;
+            }
+        };
+
+        println!("\nChanged: {}", changed.iter().map(|path| format!("'{}'", path.display())).collect::<Vec<_>>().join(", "));
+        // only slots whose inputs changed are processed again, the cache keeps the rest
+        clean_batch(args, config, batch_seed, cache, true);
+    }
+}
+
+fn take_snapshot(config: &Config) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    let mut add = |path: PathBuf| {
+        if let Ok(metadata) = metadata(&path) {
+            snapshot.insert(path, (metadata.modified().ok(), metadata.len()));
+        }
+    };
+
+    add(config.paths.process_list.clone());
+    if let Ok(dir) = config.paths.bucket.read_dir() {
+        for entry in dir.flatten() {
+            add(entry.path());
+        }
+    }
+
+    snapshot
+}
+
+fn changed_files(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let changed = new.iter().filter(|(path, state)| old.get(*path) != Some(state)).map(|(path, _)| path.clone());
+    let removed = old.keys().filter(|path| !new.contains_key(*path)).cloned();
+
+    changed.chain(removed).collect()
+}
@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{config::UnknownGamePolicy, name::NamePolicy};
+
+// flags shared by every command that reads the event config, each one overrides the matching config entry
+#[derive(Debug, Default, Args)]
+pub struct ConfigArgs {
+    /// Config file to use instead of ./multiarchi.toml
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Directory containing the submitted yamls
+    #[arg(long)]
+    pub bucket: Option<PathBuf>,
+    /// Directory processed bucket files are moved to
+    #[arg(long)]
+    pub used: Option<PathBuf>,
+    /// Directory the processed yamls are written to
+    #[arg(long)]
+    pub dist: Option<PathBuf>,
+    /// List of names and bucket ids to process
+    #[arg(long)]
+    pub process_list: Option<PathBuf>,
+    /// Output list with the games and notes of every slot
+    #[arg(long)]
+    pub output_list: Option<PathBuf>,
+    /// Output for the bot
+    #[arg(long)]
+    pub bot_output: Option<PathBuf>,
+    /// JSON file the warnings are exported to
+    #[arg(long)]
+    pub warnings_output: Option<PathBuf>,
+    /// JSON file the odds of weighted game rolls are written to
+    #[arg(long)]
+    pub rolls_output: Option<PathBuf>,
+    /// Journal of bucket files moved to the used directory
+    #[arg(long)]
+    pub journal: Option<PathBuf>,
+    /// Index of ingested file names and their bucket ids
+    #[arg(long)]
+    pub ingest_index: Option<PathBuf>,
+    /// Cache of processed slots, reused when their inputs haven't changed
+    #[arg(long)]
+    pub cache: Option<PathBuf>,
+    /// JSON file the point breakdown of every slot is written to
+    #[arg(long)]
+    pub points_output: Option<PathBuf>,
+    /// Markdown file the end of run summary is written to
+    #[arg(long)]
+    pub summary_output: Option<PathBuf>,
+    /// What to do with slot names that break Archipelago's rules
+    #[arg(long, value_enum)]
+    pub name_policy: Option<NamePolicy>,
+    /// What to do with games that aren't valid in weighted game rolls
+    #[arg(long, value_enum)]
+    pub unknown_games: Option<UnknownGamePolicy>,
+    /// Roll every weighted option instead of only the ones that need it
+    #[arg(long)]
+    pub roll_options: bool,
+}
+
+impl ConfigArgs {
+    // in the same order as `Paths::all_mut`
+    pub fn path_overrides(&self) -> [Option<&PathBuf>; 13] {
+        [
+            self.bucket.as_ref(),
+            self.used.as_ref(),
+            self.dist.as_ref(),
+            self.process_list.as_ref(),
+            self.output_list.as_ref(),
+            self.bot_output.as_ref(),
+            self.warnings_output.as_ref(),
+            self.rolls_output.as_ref(),
+            self.journal.as_ref(),
+            self.ingest_index.as_ref(),
+            self.cache.as_ref(),
+            self.points_output.as_ref(),
+            self.summary_output.as_ref(),
+        ]
+    }
+}
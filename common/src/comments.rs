@@ -1,3 +1,5 @@
+use crate::warning::{WarningKind, Warnings};
+
 #[derive(Debug)]
 pub struct Comment<'a> {
     last_key: Option<&'a str>,
@@ -49,7 +51,7 @@ pub fn get_comments(content: &str) -> Vec<Comment<'_>> {
     comments
 }
 
-pub fn insert_comments(output: String, comments: &[Comment], source: &str) -> Vec<String> {
+pub fn insert_comments(output: String, comments: &[Comment], warnings: &mut Warnings) -> Vec<String> {
     let mut lines: Vec<_> = output.lines().map(String::from).collect();
     let mut line_i = 0;
     'outer: for Comment { last_key, comment, inline, indent } in comments {
@@ -60,14 +62,14 @@ pub fn insert_comments(output: String, comments: &[Comment], source: &str) -> Ve
         }
 
         if line_i == lines.len() {
-            println!("Failed to preserve all comments from '{source}'");
+            warnings.push(WarningKind::CommentsLost, None, "failed to preserve all comments");
             break 'outer;
         }
         while find_key(&lines[line_i]) != *last_key {
             line_i += 1;
 
             if line_i == lines.len() {
-                println!("Failed to preserve all comments from '{source}'");
+                warnings.push(WarningKind::CommentsLost, None, "failed to preserve all comments");
                 break 'outer;
             }
         }
@@ -0,0 +1,195 @@
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::{args::ConfigArgs, game::GAME_RENAMES, name::NamePolicy, points::PointRules, valid_games::VALID_GAMES};
+
+pub const DEFAULT_CONFIG_PATH: &str = "./multiarchi.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub paths: Paths,
+    pub names: NameConfig,
+    pub games: GameConfig,
+    pub points: PointRules,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NameConfig {
+    pub policy: NamePolicy,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    pub unknown: UnknownGamePolicy,
+    pub roll_options: bool,
+    // only these games can be played in the event, every valid game if empty
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl GameConfig {
+    // why a game can't be played in this event, on top of it being a valid game
+    pub fn ban_reason(&self, game: &str) -> Option<&'static str> {
+        if self.deny.iter().any(|denied| denied == game) {
+            Some("is banned in this event")
+        } else if !self.allow.is_empty() && !self.allow.iter().any(|allowed| allowed == game) {
+            Some("is not in the list of games allowed in this event")
+        } else {
+            None
+        }
+    }
+
+    // yamls are checked against the current names of games, so the lists are renamed the same way and games that can never match are reported
+    fn check_games(&mut self, path: &Path) {
+        for (list, games) in [("allow", &mut self.allow), ("deny", &mut self.deny)] {
+            for game in games.iter_mut() {
+                if let Some(renamed) = GAME_RENAMES.get(game.as_str()) {
+                    *game = renamed.to_string();
+                }
+            }
+
+            let unknown: Vec<_> = games.iter().map(String::as_str).filter(|game| !VALID_GAMES.contains(game)).collect();
+            if !unknown.is_empty() {
+                println!("'{}' lists games under [games] {list} that aren't valid games: {}", path.display(), unknown.join(", "));
+            }
+        }
+    }
+}
+
+// what to do when a weighted game roll includes games that aren't in VALID_GAMES
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum UnknownGamePolicy {
+    /// Remove unknown games from the roll and warn
+    #[default]
+    Reroll,
+    /// Report an error and don't write the yaml
+    Reject,
+    /// Keep unknown games in the roll and warn
+    Warn,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    pub bucket: PathBuf,
+    pub used: PathBuf,
+    pub dist: PathBuf,
+    pub process_list: PathBuf,
+    pub output_list: PathBuf,
+    pub output_bot: PathBuf,
+    pub output_warnings: PathBuf,
+    pub output_rolls: PathBuf,
+    pub journal: PathBuf,
+    pub ingest_index: PathBuf,
+    pub cache: PathBuf,
+    pub output_points: PathBuf,
+    pub output_summary: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            bucket: PathBuf::from("./bucket"),
+            used: PathBuf::from("./used"),
+            dist: PathBuf::from("./dist"),
+            process_list: PathBuf::from("./process.tsv"),
+            output_list: PathBuf::from("./output.tsv"),
+            output_bot: PathBuf::from("./bot_output.jsonl"),
+            output_warnings: PathBuf::from("./warnings.json"),
+            output_rolls: PathBuf::from("./game_rolls.json"),
+            journal: PathBuf::from("./move_journal.tsv"),
+            ingest_index: PathBuf::from("./ingest_index.tsv"),
+            cache: PathBuf::from("./process_cache.json"),
+            output_points: PathBuf::from("./points.json"),
+            output_summary: PathBuf::from("./summary.md"),
+        }
+    }
+}
+
+impl Config {
+    // loads the config given by `--config`, or `multiarchi.toml` in the working directory if it exists, then applies flags on top
+    pub fn load(args: &ConfigArgs) -> Config {
+        let mut config = if let Some(path) = &args.config {
+            Config::from_file(path)
+        } else if Path::new(DEFAULT_CONFIG_PATH).is_file() {
+            Config::from_file(Path::new(DEFAULT_CONFIG_PATH))
+        } else {
+            Config::default()
+        };
+
+        config.paths.apply_flags(args);
+
+        if let Some(policy) = args.name_policy {
+            config.names.policy = policy;
+        }
+
+        if let Some(policy) = args.unknown_games {
+            config.games.unknown = policy;
+        }
+
+        if args.roll_options {
+            config.games.roll_options = true;
+        }
+
+        config
+    }
+
+    pub fn from_file(path: &Path) -> Config {
+        let content = read_to_string(path).unwrap_or_else(|err| panic!("Failed to read config '{}': {err}", path.display()));
+        let mut config: Config = toml::from_str(&content).unwrap_or_else(|err| panic!("Failed to parse config '{}': {err}", path.display()));
+
+        // paths in a config file are relative to the file, so each event directory can carry its own config
+        if let Some(base) = path.parent() {
+            config.paths.rebase(base);
+        }
+
+        config.games.check_games(path);
+
+        config
+    }
+}
+
+impl Paths {
+    fn all_mut(&mut self) -> [&mut PathBuf; 13] {
+        [
+            &mut self.bucket,
+            &mut self.used,
+            &mut self.dist,
+            &mut self.process_list,
+            &mut self.output_list,
+            &mut self.output_bot,
+            &mut self.output_warnings,
+            &mut self.output_rolls,
+            &mut self.journal,
+            &mut self.ingest_index,
+            &mut self.cache,
+            &mut self.output_points,
+            &mut self.output_summary,
+        ]
+    }
+
+    fn rebase(&mut self, base: &Path) {
+        for path in self.all_mut() {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        }
+    }
+
+    fn apply_flags(&mut self, args: &ConfigArgs) {
+        for (path, value) in self.all_mut().into_iter().zip(args.path_overrides()) {
+            if let Some(value) = value {
+                *path = value.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use yaml_rust2::{Yaml, YamlLoader};
+
+use crate::{pipeline::trim_yaml, util::stable_hash};
+
+// hashes the content of a submission, ignoring the name, comments and key order, so resubmissions with a new name are still recognized
+pub fn fingerprint(content: &str) -> Option<u64> {
+    let documents = YamlLoader::load_from_str(trim_yaml(content)).ok()?;
+    let name_key = Yaml::from_str("name");
+
+    let mut canonical = String::new();
+    for doc in &documents {
+        let doc = match doc {
+            Yaml::Hash(hash) => {
+                let mut hash = hash.clone();
+                hash.remove(&name_key);
+                Yaml::Hash(hash)
+            }
+            _ => doc.clone(),
+        };
+
+        write_canonical(&mut canonical, &doc);
+        canonical.push('\n');
+    }
+
+    Some(stable_hash(canonical.as_bytes()))
+}
+
+fn write_canonical(buf: &mut String, yaml: &Yaml) {
+    match yaml {
+        Yaml::Real(str) => buf.push_str(&format!("f{str}")),
+        Yaml::Integer(int) => buf.push_str(&format!("i{int}")),
+        Yaml::String(str) => buf.push_str(&format!("s{}:{str}", str.len())),
+        Yaml::Boolean(bool) => buf.push_str(&format!("b{bool}")),
+        Yaml::Array(yamls) => {
+            buf.push('[');
+            for yaml in yamls {
+                write_canonical(buf, yaml);
+                buf.push(',');
+            }
+            buf.push(']');
+        }
+        Yaml::Hash(hash) => {
+            let mut entries: Vec<_> = hash
+                .iter()
+                .map(|(key, value)| {
+                    let mut key_buf = String::new();
+                    write_canonical(&mut key_buf, key);
+                    let mut value_buf = String::new();
+                    write_canonical(&mut value_buf, value);
+                    (key_buf, value_buf)
+                })
+                .collect();
+            entries.sort();
+
+            buf.push('{');
+            for (key, value) in entries {
+                buf.push_str(&key);
+                buf.push('=');
+                buf.push_str(&value);
+                buf.push(',');
+            }
+            buf.push('}');
+        }
+        Yaml::Alias(alias) => buf.push_str(&format!("a{alias}")),
+        Yaml::Null => buf.push('~'),
+        Yaml::BadValue => buf.push('!'),
+    }
+}
@@ -0,0 +1,196 @@
+use hashlink::LinkedHashMap;
+use phf::phf_map;
+use rand::Rng;
+use rand_distr::{Distribution, WeightedIndex};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use yaml_rust2::Yaml;
+
+use crate::{
+    config::{GameConfig, UnknownGamePolicy},
+    triggers::rename_game_in_triggers,
+    util::as_i64,
+    valid_games::VALID_GAMES,
+    warning::{WarningKind, Warnings},
+};
+
+pub(crate) const GAME_RENAMES: phf::Map<&'static str, &'static str> = phf_map!(
+    "PokéPark Wii: Pikachu's Adventure [JP]" => "PokePark",
+    "PokéPark" => "PokePark",
+    "Paper Mario The Thousand Year Door" => "Paper Mario: The Thousand-Year Door",
+    "Metroid Zero Mission" => "Metroid: Zero Mission",
+    "Plants vs. Zombies: Replanted" => "Plants vs. Zombies",
+    "The Simpsons Hit And Run" => "Simpsons Hit and Run"
+);
+
+// the rolls of a file, numbered by document
+pub type GameRolls = Vec<(usize, GameRoll)>;
+
+// the odds of a weighted game roll, kept so hosts can show a player how their game was picked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRoll {
+    // every entry of the roll as submitted, including the ones that couldn't be picked
+    pub entries: Vec<RollEntry>,
+    pub winner: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollEntry {
+    pub game: String,
+    pub weight: i64,
+    // why the game couldn't be picked
+    pub removed: Option<String>,
+}
+
+impl GameRoll {
+    // the odds of every game that could be picked
+    pub fn probabilities(&self) -> Vec<(&str, f64)> {
+        let candidates: Vec<_> = self.entries.iter().filter(|entry| entry.removed.is_none()).collect();
+        let total: i64 = candidates.iter().map(|entry| entry.weight).sum();
+        candidates.iter().map(|entry| (entry.game.as_str(), entry.weight as f64 / total as f64)).collect()
+    }
+
+    pub fn removed(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().filter_map(|entry| entry.removed.as_deref().map(|reason| (entry.game.as_str(), reason)))
+    }
+
+    pub fn to_json(&self, document: usize) -> Value {
+        let probabilities = self.probabilities();
+        json!({
+            "document": document,
+            "entries": self
+                .entries
+                .iter()
+                .map(|entry| {
+                    let probability = probabilities.iter().find(|(game, _)| *game == entry.game).map(|(_, probability)| probability);
+                    json!({ "game": entry.game, "weight": entry.weight, "probability": probability, "removed": entry.removed })
+                })
+                .collect::<Vec<_>>(),
+            "winner": self.winner,
+        })
+    }
+}
+
+// the game roll was refused, the reason has already been added to the warnings
+pub struct Rejected;
+
+pub fn choose_game<R: Rng>(doc: &mut Yaml, config: &GameConfig, roll: &mut Option<GameRoll>, warnings: &mut Warnings, rng: &mut R) -> Result<Option<Yaml>, Rejected> {
+    let game_key = Yaml::from_str("game");
+
+    if let Some(hash) = doc.as_mut_hash() {
+        for (old, new) in GAME_RENAMES.entries() {
+            rename_game(hash, old, new);
+        }
+    }
+
+    for (old, new) in GAME_RENAMES.entries() {
+        rename_game_in_triggers(doc, old, new);
+    }
+
+    let Some(games) = doc.as_mut_hash().and_then(|hash| hash.get_mut(&game_key)) else {
+        return Ok(None);
+    };
+
+    let game = match games {
+        Yaml::Hash(games) => {
+            let mut rejected = false;
+            let mut entries = vec![];
+
+            for (game, weight) in games.iter() {
+                let Some(game) = game.as_str() else {
+                    continue;
+                };
+                let game = GAME_RENAMES.get(game).copied().unwrap_or(game);
+                let Some(weight) = as_i64(weight) else {
+                    entries.push(RollEntry {
+                        game: game.to_string(),
+                        weight: 0,
+                        removed: Some("the weight is not a number".to_string()),
+                    });
+                    continue;
+                };
+
+                let removed = if weight < 0 {
+                    warnings.push(WarningKind::GameWeights, Some(game), format!("has a negative weight of {weight} in the game roll"));
+                    rejected = true;
+                    Some("negative weight".to_string())
+                } else if weight == 0 {
+                    Some("zero weight".to_string())
+                } else {
+                    None
+                };
+                entries.push(RollEntry {
+                    game: game.to_string(),
+                    weight,
+                    removed,
+                });
+            }
+
+            let positive_games = entries.iter().filter(|entry| entry.removed.is_none()).count();
+            let policy = config.unknown;
+            for entry in entries.iter_mut().filter(|entry| entry.removed.is_none() && !VALID_GAMES.contains(&entry.game.as_str())) {
+                let game = Some(entry.game.as_str());
+                match policy {
+                    UnknownGamePolicy::Reroll => {
+                        warnings.push(WarningKind::UnknownGame, game, "is not a valid game, it was removed from the game roll");
+                        entry.removed = Some("not a valid game".to_string());
+                    }
+                    UnknownGamePolicy::Reject => {
+                        warnings.push(WarningKind::InvalidGame, game, "is not a valid game, the game roll was rejected");
+                        rejected = true;
+                    }
+                    UnknownGamePolicy::Warn => warnings.push(WarningKind::UnknownGame, game, "is not a valid game, it was kept in the game roll"),
+                }
+            }
+
+            let rolled_games = entries.iter().filter(|entry| entry.removed.is_none()).count();
+            for entry in entries.iter_mut().filter(|entry| entry.removed.is_none()) {
+                if let Some(reason) = config.ban_reason(&entry.game) {
+                    warnings.push(WarningKind::BannedInRoll, Some(&entry.game), format!("{reason}, it was removed from the game roll"));
+                    entry.removed = Some(reason.to_string());
+                }
+            }
+
+            if rejected {
+                return Err(Rejected);
+            }
+
+            let candidates: Vec<_> = entries.iter().filter(|entry| entry.removed.is_none()).collect();
+            if candidates.is_empty() {
+                if rolled_games > 0 {
+                    warnings.push(WarningKind::BannedGame, None, "every game in the game roll is banned in this event, the yaml was rejected");
+                } else if positive_games > 0 {
+                    warnings.push(WarningKind::InvalidGame, None, "no valid game is left in the game roll, the yaml was rejected");
+                } else {
+                    warnings.push(WarningKind::GameWeights, None, "no game in the game roll has a positive weight");
+                }
+                return Err(Rejected);
+            }
+
+            let dist = WeightedIndex::new(candidates.iter().map(|entry| entry.weight)).expect("Weights should be positive");
+            let winner = candidates[dist.sample(rng)].game.clone();
+            *roll = Some(GameRoll { entries, winner: winner.clone() });
+            Yaml::String(winner)
+        }
+        Yaml::String(game) => {
+            if let Some(new_name) = GAME_RENAMES.get(game) {
+                *game = String::from(*new_name);
+            }
+            if let Some(reason) = config.ban_reason(game) {
+                warnings.push(WarningKind::BannedGame, Some(game), format!("{reason}, the yaml was rejected"));
+                return Err(Rejected);
+            }
+            Yaml::from_str(game)
+        }
+        _ => return Ok(None),
+    };
+
+    *games = game.clone();
+    Ok(Some(game))
+}
+
+fn rename_game(hash: &mut LinkedHashMap<Yaml, Yaml>, from: &str, to: &str) {
+    if let Some(options) = hash.remove(&Yaml::from_str(from)) {
+        hash.insert(Yaml::from_str(to), options);
+    }
+}
@@ -1,7 +1,17 @@
+pub mod args;
 pub mod comments;
+pub mod config;
+pub mod fingerprint;
+pub mod game;
 pub mod name;
+pub mod pipeline;
+pub mod points;
+pub mod roll;
 pub mod special;
+pub mod summary;
+pub mod triggers;
 pub mod util;
 pub mod valid_games;
 pub mod write;
 pub mod name_changes;
+pub mod warning;
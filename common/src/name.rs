@@ -1,7 +1,118 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use clap::ValueEnum;
+use serde::Deserialize;
 use yaml_rust2::Yaml;
 
+use crate::{
+    util::stable_hash,
+    warning::{WarningKind, Warnings},
+};
+
+pub const MAX_NAME_LENGTH: usize = 16;
+// braces would be read as Archipelago name placeholders, the rest can't be used in the name of the dist file
+// games whose yamls can't be renamed, so their slots keep the name they were submitted with
+pub const UNNAMED_GAMES: [&str; 2] = ["Chrono Trigger Jets of Time", "Final Fantasy"];
+
+const FORBIDDEN_NAME_CHARS: [char; 11] = ['{', '}', '/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum NamePolicy {
+    /// Report the name and don't write the yaml
+    Error,
+    /// Report the name and keep it
+    #[default]
+    Warn,
+    /// Report the name and shorten it
+    Shorten,
+}
+
+// tracks every slot name handed out in a batch, so names that only collide after suffixing are caught
+#[derive(Debug, Default)]
+pub struct NameRules {
+    pub policy: NamePolicy,
+    taken: HashSet<String>,
+}
+
+impl NameRules {
+    pub fn new(policy: NamePolicy) -> Self {
+        NameRules { policy, taken: HashSet::new() }
+    }
+
+    // returns the name to use for a slot, or None if the name breaks the rules and the policy is to reject it
+    pub fn resolve(&mut self, base: &str, suffix: Option<usize>, warnings: &mut Warnings) -> Option<String> {
+        let suffix = suffix.map(|suffix| suffix.to_string()).unwrap_or_default();
+        let name = format!("{base}{suffix}");
+        let problems = self.problems(&name);
+
+        if problems.is_empty() {
+            self.taken.insert(name.clone());
+            return Some(name);
+        }
+
+        match self.policy {
+            NamePolicy::Error => {
+                warnings.push(WarningKind::InvalidName, None, format!("name '{name}' {}", problems.join(", ")));
+                None
+            }
+            NamePolicy::Warn => {
+                warnings.push(WarningKind::NameRules, None, format!("name '{name}' {}", problems.join(", ")));
+                self.taken.insert(name.clone());
+                Some(name)
+            }
+            NamePolicy::Shorten => {
+                let shortened = self.shorten(base, &suffix);
+                warnings.push(WarningKind::NameRules, None, format!("name '{name}' {}, shortened to '{shortened}'", problems.join(", ")));
+                self.taken.insert(shortened.clone());
+                Some(shortened)
+            }
+        }
+    }
+
+    fn problems(&self, name: &str) -> Vec<String> {
+        let mut problems = vec![];
+
+        if name.trim().is_empty() {
+            problems.push(String::from("is empty"));
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            problems.push(format!("is longer than {MAX_NAME_LENGTH} characters"));
+        }
+        if name.trim() != name {
+            problems.push(String::from("has leading or trailing whitespace"));
+        }
+        if name.chars().any(|char| char.is_control() || FORBIDDEN_NAME_CHARS.contains(&char)) {
+            problems.push(String::from("contains forbidden characters"));
+        }
+        if self.taken.contains(name) {
+            problems.push(String::from("is already used by another slot"));
+        }
+
+        problems
+    }
+
+    // strips forbidden characters and truncates the base so the suffix survives, then counts up until the name is free
+    fn shorten(&self, base: &str, suffix: &str) -> String {
+        let cleaned: String = base.chars().filter(|char| !char.is_control() && !FORBIDDEN_NAME_CHARS.contains(char)).collect();
+        let cleaned = cleaned.trim();
+        let cleaned = if cleaned.is_empty() { "Player" } else { cleaned };
+
+        let mut counter = 1;
+        loop {
+            let tail = if counter == 1 { suffix.to_string() } else { format!("{suffix}_{counter}") };
+            let base: String = cleaned.chars().take(MAX_NAME_LENGTH.saturating_sub(tail.chars().count())).collect();
+            let name = format!("{}{tail}", base.trim_end());
+
+            if !self.taken.contains(&name) {
+                return name;
+            }
+
+            counter += 1;
+        }
+    }
+}
+
 pub fn set_name(doc: &mut Yaml, name: &str, game: Option<&Yaml>) -> Option<Yaml> {
     let name_key = Yaml::from_str("name");
     let triggers_key = Yaml::from_str("triggers");
@@ -81,12 +192,75 @@ pub fn strip_name_changes_from_triggers(doc: &mut Yaml) {
     }
 }
 
-pub fn rename_plando_worlds(mapping: &HashMap<Yaml, Yaml>, docs: &mut [Yaml], name: &str) {
+// every slot name handed out in a batch, so plandos into other submissions can follow their renames
+#[derive(Debug, Default)]
+pub struct BatchNames {
+    worlds: HashMap<Yaml, Yaml>,
+    ambiguous: HashSet<Yaml>,
+    assigned: HashSet<String>,
+    item_links: HashMap<String, Vec<String>>,
+}
+
+impl BatchNames {
+    pub fn add_submission(&mut self, slot: &str, docs: &[Yaml], new_names: &[Option<String>]) {
+        let name_key = Yaml::from_str("name");
+
+        for (doc, new_name) in docs.iter().zip(new_names) {
+            let Some(new_name) = new_name else {
+                continue;
+            };
+
+            let old_name = doc.as_hash().and_then(|hash| hash.get(&name_key)).filter(|old_name| old_name.as_str().is_some());
+
+            // a weighted game is only known once it's rolled, so only documents that always are one of these games keep their name here
+            if doc["game"].as_str().is_some_and(|game| UNNAMED_GAMES.contains(&game)) {
+                if let Some(old_name) = old_name.and_then(Yaml::as_str) {
+                    self.assigned.insert(old_name.to_string());
+                }
+            } else if let Some(old_name) = old_name {
+                self.assigned.insert(new_name.clone());
+
+                let new_name = Yaml::String(new_name.clone());
+                if self.worlds.get(old_name).is_some_and(|existing| *existing != new_name) {
+                    self.ambiguous.insert(old_name.clone());
+                } else {
+                    self.worlds.insert(old_name.clone(), new_name);
+                }
+            } else {
+                self.assigned.insert(new_name.clone());
+            }
+
+            for link in item_link_names(doc) {
+                let slots = self.item_links.entry(link).or_default();
+                if !slots.iter().any(|existing| existing == slot) {
+                    slots.push(slot.to_string());
+                }
+            }
+        }
+    }
+
+    // changes whenever a name a plando or item link could refer to changes
+    pub fn fingerprint(&self) -> u64 {
+        let mut entries: Vec<_> = self.worlds.iter().map(|(old, new)| format!("world\t{old:?}\t{new:?}")).collect();
+        entries.extend(self.ambiguous.iter().map(|world| format!("ambiguous\t{world:?}")));
+        entries.extend(self.assigned.iter().map(|name| format!("assigned\t{name}")));
+        entries.extend(self.item_links.iter().map(|(link, slots)| format!("item_link\t{link}\t{}", slots.join("\t"))));
+        entries.sort();
+
+        stable_hash(entries.join("\n").as_bytes())
+    }
+
+    fn is_known_world(&self, world: &str) -> bool {
+        self.assigned.contains(world) || self.item_links.contains_key(world)
+    }
+}
+
+pub fn rename_plando_worlds(mapping: &HashMap<Yaml, Yaml>, batch: &BatchNames, docs: &mut [Yaml], warnings: &mut Warnings) {
     let game_key = Yaml::from_str("game");
     let plando_key = Yaml::from_str("plando_items");
     let world_key = Yaml::from_str("world");
 
-    for doc in docs {
+    for doc in docs.iter_mut() {
         if let Some(hash) = doc.as_mut_hash()
             && let Some(options_key) = hash.get(&game_key).cloned()
             && let Some(plando_items) = hash
@@ -97,21 +271,74 @@ pub fn rename_plando_worlds(mapping: &HashMap<Yaml, Yaml>, docs: &mut [Yaml], na
         {
             for plando_block in plando_items {
                 if let Some(world) = plando_block.as_mut_hash().and_then(|hash| hash.get_mut(&world_key)) {
-                    if world.as_bool().is_none() {
-                        println!("'{name}.yaml' contains a plando into a named world");
-                    }
-
-                    if let Some(new_name) = mapping.get(world) {
-                        *world = new_name.clone();
-                    } else if let Some(worlds) = world.as_mut_vec() {
+                    if let Some(worlds) = world.as_mut_vec() {
                         for world in worlds {
-                            if let Some(new_name) = mapping.get(world) {
-                                *world = new_name.clone();
-                            }
+                            rename_world(mapping, batch, world, options_key.as_str(), warnings);
                         }
+                    } else if world.as_bool().is_none() {
+                        rename_world(mapping, batch, world, options_key.as_str(), warnings);
                     }
                 }
             }
         }
     }
+
+    for doc in docs.iter() {
+        let game = doc.as_hash().and_then(|hash| hash.get(&game_key)).and_then(Yaml::as_str);
+
+        for link in item_link_names(doc) {
+            if batch.assigned.contains(&link) {
+                warnings.push(WarningKind::ItemLink, game, format!("item link '{link}' has the same name as a slot"));
+            } else if batch.item_links.get(&link).is_some_and(|slots| slots.len() < 2) {
+                warnings.push(WarningKind::ItemLink, game, format!("item link '{link}' is not shared with any other yaml in the batch"));
+            }
+        }
+    }
+}
+
+// renames a single plando target, preferring names from the same file over names from the rest of the batch
+fn rename_world(mapping: &HashMap<Yaml, Yaml>, batch: &BatchNames, world: &mut Yaml, game: Option<&str>, warnings: &mut Warnings) {
+    let old = to_world_name(world);
+
+    if let Some(new_name) = mapping.get(world) {
+        *world = new_name.clone();
+    } else if batch.ambiguous.contains(world) {
+        warnings.push(
+            WarningKind::UnresolvedWorld,
+            game,
+            format!("contains a plando into '{old}', which is the name of more than one yaml in the batch"),
+        );
+    } else if let Some(new_name) = batch.worlds.get(world) {
+        warnings.push(WarningKind::NamedWorldPlando, game, format!("contains a plando into '{old}', now named '{}'", to_world_name(new_name)));
+        *world = new_name.clone();
+    } else if batch.is_known_world(&old) {
+        warnings.push(WarningKind::NamedWorldPlando, game, format!("contains a plando into '{old}'"));
+    } else {
+        warnings.push(
+            WarningKind::UnresolvedWorld,
+            game,
+            format!("contains a plando into '{old}', which is not a slot or item link in the batch"),
+        );
+    }
+}
+
+fn to_world_name(world: &Yaml) -> String {
+    match world {
+        Yaml::String(str) | Yaml::Real(str) => str.clone(),
+        Yaml::Integer(int) => int.to_string(),
+        _ => String::from("~"),
+    }
+}
+
+fn item_link_names(doc: &Yaml) -> Vec<String> {
+    let game_key = Yaml::from_str("game");
+    let item_links_key = Yaml::from_str("item_links");
+    let name_key = Yaml::from_str("name");
+
+    doc.as_hash()
+        .and_then(|hash| hash.get(hash.get(&game_key)?))
+        .and_then(|options| options.as_hash()?.get(&item_links_key))
+        .and_then(Yaml::as_vec)
+        .map(|links| links.iter().filter_map(|link| link.as_hash()?.get(&name_key)?.as_str().map(String::from)).collect())
+        .unwrap_or_default()
 }
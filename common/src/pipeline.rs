@@ -0,0 +1,312 @@
+use std::{collections::HashMap, fmt::Write as FmtWrite, fs::File, io::Write};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use yaml_rust2::{Yaml, YamlEmitter, YamlLoader};
+
+use crate::{
+    comments::{get_comments, insert_comments},
+    config::{GameConfig, Paths},
+    game::{GameRolls, choose_game},
+    name::{BatchNames, NameRules, UNNAMED_GAMES, rename_plando_worlds, set_name},
+    name_changes::{Mapping, remap_common_options},
+    points::{PointBonus, PointBreakdown, PointRules, option_bonuses, score, write_points},
+    special::handle_special,
+    summary::{Summary, write_summary},
+    util::stable_hash,
+    warning::{Warning, WarningKind, Warnings, warnings_to_json},
+    write::{Games, TableFormat, check_games, write_output_list_header, write_to_bot_output, write_to_output_list},
+};
+
+// the sources that decide what a processed yaml looks like, so cached results are thrown away when any of them changes
+const RULES_SOURCES: [&str; 13] = [
+    include_str!("comments.rs"),
+    include_str!("game.rs"),
+    include_str!("name.rs"),
+    include_str!("name_changes.rs"),
+    include_str!("name_mapping.tsv"),
+    include_str!("pipeline.rs"),
+    include_str!("points.rs"),
+    include_str!("roll.rs"),
+    include_str!("special.rs"),
+    include_str!("triggers.rs"),
+    include_str!("util.rs"),
+    include_str!("valid_games.rs"),
+    include_str!("write.rs"),
+];
+
+pub fn rules_version() -> u64 {
+    stable_hash(RULES_SOURCES.concat().as_bytes())
+}
+
+// everything processing a yaml needs besides the yaml itself, shared by every slot in a batch
+pub struct PipelineOptions<'a> {
+    pub mappings: &'a Mapping,
+    pub batch_names: &'a BatchNames,
+    pub games: &'a GameConfig,
+    pub points: &'a PointRules,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProcessedYaml {
+    // None if the yaml couldn't be processed or was rejected, the reason is in the warnings
+    pub text: Option<String>,
+    // the slot can't be played as submitted, so it isn't scored or listed with the playable slots
+    pub rejected: bool,
+    pub games: Games,
+    pub rolls: GameRolls,
+    pub bonuses: Vec<PointBonus>,
+}
+
+impl ProcessedYaml {
+    // for a yaml that couldn't even be read
+    pub fn failed() -> Self {
+        ProcessedYaml {
+            rejected: true,
+            ..ProcessedYaml::default()
+        }
+    }
+}
+
+// strips the byte order marks and blank lines some editors leave around a yaml
+pub fn trim_yaml(content: &str) -> &str {
+    content.trim_matches(|char: char| char == '\n' || char == '\r' || char == '\u{feff}')
+}
+
+// picks the final name of every document in a yaml, failures to parse it are reported when it is processed
+pub fn assign_names(name_rules: &mut NameRules, batch_names: &mut BatchNames, content: &str, warnings: &mut Warnings) -> Vec<Option<String>> {
+    let Ok(documents) = YamlLoader::load_from_str(trim_yaml(content)) else {
+        return vec![];
+    };
+
+    let name = warnings.slot().to_string();
+    let single_game = documents.len() == 1;
+    let new_names: Vec<_> = (0..documents.len())
+        .map(|i| name_rules.resolve(&name, if single_game { None } else { Some(i + 1) }, warnings))
+        .collect();
+
+    batch_names.add_submission(&name, &documents, &new_names);
+    new_names
+}
+
+// the name a slot is written and listed as: the name assigned to its only document, yamls with several games keep the listed name as every game gets its own
+pub fn slot_name(name: &str, new_names: &[Option<String>]) -> String {
+    match new_names {
+        [Some(new_name)] => new_name.clone(),
+        _ => name.to_string(),
+    }
+}
+
+// runs a yaml through every step between the submitted file and the dist file, `source` is only used in messages
+pub fn process_yaml<R: Rng>(content: &str, source: &str, new_names: &[Option<String>], options: &PipelineOptions, warnings: &mut Warnings, rng: &mut R) -> ProcessedYaml {
+    let name = warnings.slot().to_string();
+    let content = trim_yaml(content);
+    let mut processed = ProcessedYaml::default();
+
+    let comments = get_comments(content);
+
+    let mut documents = match YamlLoader::load_from_str(content) {
+        Ok(documents) => documents,
+        Err(err) => {
+            warnings.push(WarningKind::ParseFailed, None, format!("error when loading '{source}': {err}"));
+            processed.rejected = true;
+            return processed;
+        }
+    };
+
+    let single_game = documents.len() == 1;
+
+    let mut name_mapping = HashMap::new();
+    let mut rejected = false;
+
+    for (i, doc) in documents.iter_mut().enumerate() {
+        let new_name = new_names.get(i).cloned().flatten().unwrap_or_else(|| {
+            rejected = true;
+            if single_game { name.to_string() } else { format!("{name}{}", i + 1) }
+        });
+        let mut old_name = None;
+
+        let mut roll = None;
+        let game = choose_game(doc, options.games, &mut roll, warnings, rng).unwrap_or_else(|_| {
+            rejected = true;
+            None
+        });
+        if let Some(roll) = roll {
+            processed.rolls.push((i + 1, roll));
+        }
+
+        if let Some(game) = game {
+            let game_str = game.as_str().expect("Game should be a string");
+            if let Some((_, count, last_notes)) = processed.games.last_mut().filter(|(existing_game, _, _)| existing_game == game_str) {
+                *count += 1;
+                last_notes.extend(handle_special(doc, &game, options.games.roll_options, warnings, rng));
+            } else {
+                processed.games.push((game_str.to_string(), 1, handle_special(doc, &game, options.games.roll_options, warnings, rng)));
+            }
+
+            if let Some(game_hash) = doc[game_str].as_hash() {
+                processed.bonuses.extend(option_bonuses(game_hash, game_str, processed.games.len() - 1, options.points));
+            }
+
+            if UNNAMED_GAMES.contains(&game_str) {
+                warnings.push(WarningKind::NameNotSet, Some(game_str), format!("contains a {game_str}"));
+            } else {
+                old_name = set_name(doc, &new_name, Some(&game));
+            }
+
+            remap_common_options(options.mappings, doc, game_str);
+        } else {
+            old_name = set_name(doc, &new_name, None);
+        }
+
+        if let Some(old_name) = old_name {
+            name_mapping.insert(old_name, Yaml::String(new_name));
+        }
+    }
+
+    rename_plando_worlds(&name_mapping, options.batch_names, &mut documents, warnings);
+
+    if documents.len() > 8 {
+        warnings.push(WarningKind::TooManyGames, None, format!("contains {} games", documents.len()));
+    }
+
+    if rejected {
+        processed.rejected = true;
+        return processed;
+    }
+
+    let mut output_buf = String::new();
+
+    for doc in documents {
+        let mut emitter = YamlEmitter::new(&mut output_buf);
+        let _ = emitter.dump(&doc);
+        let _ = output_buf.write_char('\n');
+    }
+
+    processed.text = Some(insert_comments(output_buf, &comments, warnings).join("\n"));
+    processed
+}
+
+// the files every batch writes besides the dist yamls, created up front so a bad working directory fails before any processing
+pub struct OutputWriters {
+    output_list: File,
+    bot_output: File,
+}
+
+impl OutputWriters {
+    pub fn create(paths: &Paths) -> Self {
+        let output_list = match File::create(&paths.output_list) {
+            Ok(writer) => writer,
+            Err(err) => {
+                panic!("Error when creating output file: {err}");
+            }
+        };
+
+        let bot_output = match File::create(&paths.output_bot) {
+            Ok(writer) => writer,
+            Err(err) => {
+                panic!("Error when creating bot output file: {err}");
+            }
+        };
+
+        OutputWriters { output_list, bot_output }
+    }
+}
+
+// everything a batch reports about its slots, filled in slot by slot so every command writes the same outputs
+pub struct BatchOutput {
+    format: TableFormat,
+    output_list: Vec<u8>,
+    bot_output: Vec<u8>,
+    points: Vec<Value>,
+    rolls: Vec<Value>,
+    summary: Summary,
+    pub warnings: Vec<Warning>,
+}
+
+impl BatchOutput {
+    pub fn new(paths: &Paths) -> Self {
+        let format = TableFormat::from_path(&paths.output_list);
+        let mut output_list = vec![];
+        write_output_list_header(&mut output_list, format);
+
+        BatchOutput {
+            format,
+            output_list,
+            bot_output: vec![],
+            points: vec![],
+            rolls: vec![],
+            summary: Summary::default(),
+            warnings: vec![],
+        }
+    }
+
+    // checks and scores a processed slot and adds it to every output, `warnings` should hold everything else reported about the slot
+    //
+    // rejected slots only get a bot output record and a place in the summary, they have no games to check or score
+    pub fn add_slot(&mut self, name: &str, bucket_id: Option<&str>, seed: u64, processed: &ProcessedYaml, rules: &PointRules, mut warnings: Warnings) -> Option<PointBreakdown> {
+        warnings.set_slot(name);
+        let games = &processed.games;
+        let points = if processed.rejected {
+            self.summary.add_rejected(name, bucket_id);
+            None
+        } else {
+            check_games(games, &mut warnings);
+
+            let points = score(games, &processed.bonuses, rules);
+            write_to_output_list(&mut self.output_list, self.format, name, games, points.total, seed);
+            self.summary.add(name, bucket_id, games, points.total);
+            self.points.push(json!({
+                "slot": name,
+                "bucket_id": bucket_id,
+                "points": points,
+            }));
+            Some(points)
+        };
+
+        if !processed.rolls.is_empty() {
+            self.rolls.push(json!({
+                "slot": name,
+                "bucket_id": bucket_id,
+                "seed": seed,
+                "rolls": processed.rolls.iter().map(|(document, roll)| roll.to_json(*document)).collect::<Vec<_>>(),
+            }));
+        }
+
+        let warnings = warnings.into_vec();
+        write_to_bot_output(&mut self.bot_output, name, bucket_id, games, points.as_ref().map(|points| points.total), &warnings);
+        self.warnings.extend(warnings);
+
+        points
+    }
+
+    pub fn summary(&self) -> String {
+        self.summary.to_markdown(&self.warnings)
+    }
+
+    pub fn output_list(&self) -> &[u8] {
+        &self.output_list
+    }
+
+    pub fn bot_output(&self) -> &[u8] {
+        &self.bot_output
+    }
+
+    pub fn write(self, paths: &Paths, mut writers: OutputWriters) {
+        if let Err(err) = writers.output_list.write_all(&self.output_list) {
+            println!("Failed to write to output file: {err}");
+        }
+        if let Err(err) = writers.bot_output.write_all(&self.bot_output) {
+            println!("Failed to write to bot output file: {err}");
+        }
+        if let Err(err) = File::create(&paths.output_warnings).and_then(|mut writer| writer.write_all(warnings_to_json(&self.warnings).to_string().as_bytes())) {
+            println!("Failed to write to warnings file: {err}");
+        }
+        if let Err(err) = File::create(&paths.output_rolls).and_then(|mut writer| writer.write_all(Value::Array(self.rolls).to_string().as_bytes())) {
+            println!("Failed to write to game rolls file: {err}");
+        }
+        write_points(&paths.output_points, self.points);
+        write_summary(&paths.output_summary, &self.summary.to_markdown(&self.warnings));
+    }
+}
@@ -0,0 +1,305 @@
+use std::{collections::HashMap, fs::File, io::Write, path::Path};
+
+use hashlink::LinkedHashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use yaml_rust2::Yaml;
+
+use crate::{
+    special::{option_can_be, option_can_be_at_least},
+    write::Note,
+};
+
+// how slots are scored, set per event under `[points]`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PointRules {
+    // points every slot gets before its games are counted
+    pub base: u32,
+    // points per copy of a game that has no value in `games`
+    pub default: u32,
+    pub games: HashMap<String, u32>,
+    // games that are neither scored nor counted towards the cap
+    pub skipped: Vec<String>,
+    // games after this many counted games don't score, a game that crosses it still scores all of its copies
+    pub cap: u32,
+    pub modifiers: Vec<PointModifier>,
+}
+
+// extra points for a copy of a game whose option can be set to a large configuration, checked after migrations and rolls
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PointModifier {
+    pub game: String,
+    pub option: String,
+    // applies if the option can be any of these values
+    #[serde(default)]
+    pub values: Vec<String>,
+    // applies if the option can be a number of at least this
+    pub at_least: Option<i64>,
+    pub points: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PointBonus {
+    pub game: String,
+    // the index of the games entry the bonus belongs to, so a game listed more than once only gets the bonuses of its own documents
+    pub entry: usize,
+    pub reason: String,
+    pub points: u32,
+}
+
+impl Default for PointRules {
+    fn default() -> Self {
+        let games = [
+            ("Clique", 0),
+            ("Autopelago", 0),
+            ("ArchipIDLE", 0),
+            ("Archipelago", 0),
+            ("APBingo", 0),
+            ("Keymaster's Keep", 2),
+            ("Stardew Valley", 2),
+        ];
+
+        PointRules {
+            base: 1,
+            default: 1,
+            games: games.into_iter().map(|(game, points)| (game.to_string(), points)).collect(),
+            skipped: vec!["Clique".to_string()],
+            cap: 8,
+            modifiers: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameStatus {
+    Counted,
+    Skipped,
+    OverCap,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GamePoints {
+    pub game: String,
+    pub count: u32,
+    // points per copy of the game
+    pub value: u32,
+    pub bonuses: Vec<PointBonus>,
+    pub points: u32,
+    pub status: GameStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PointBreakdown {
+    pub base: u32,
+    pub games: Vec<GamePoints>,
+    pub total: u32,
+}
+
+impl PointRules {
+    fn value_of(&self, game: &str) -> u32 {
+        self.games.get(game).copied().unwrap_or(self.default)
+    }
+}
+
+// the modifiers that apply to one document, `game_hash` being the options of its game and `entry` the games entry it was counted in
+pub fn option_bonuses(game_hash: &LinkedHashMap<Yaml, Yaml>, game: &str, entry: usize, rules: &PointRules) -> Vec<PointBonus> {
+    rules
+        .modifiers
+        .iter()
+        .filter(|modifier| modifier.game == game)
+        .filter_map(|modifier| {
+            let value = modifier
+                .values
+                .iter()
+                .find(|value| option_can_be(game_hash, &modifier.option, &Yaml::BadValue, &Yaml::from_str(value)))
+                .map(|value| format!("{} can be {value}", modifier.option))
+                .or_else(|| {
+                    modifier
+                        .at_least
+                        .filter(|minimum| option_can_be_at_least(game_hash, &modifier.option, *minimum))
+                        .map(|minimum| format!("{} can be at least {minimum}", modifier.option))
+                })?;
+
+            Some(PointBonus {
+                game: game.to_string(),
+                entry,
+                reason: value,
+                points: modifier.points,
+            })
+        })
+        .collect()
+}
+
+pub fn score(games: &[(String, u32, Vec<Note>)], bonuses: &[PointBonus], rules: &PointRules) -> PointBreakdown {
+    let mut counted_games = 0;
+    let mut total = rules.base;
+
+    let games = games
+        .iter()
+        .enumerate()
+        .map(|(entry, (game, count, _))| {
+            let value = rules.value_of(game);
+            let status = if rules.skipped.contains(game) {
+                GameStatus::Skipped
+            } else if counted_games >= rules.cap {
+                GameStatus::OverCap
+            } else {
+                GameStatus::Counted
+            };
+
+            let bonuses: Vec<_> = bonuses.iter().filter(|bonus| bonus.entry == entry).cloned().collect();
+            let points = if status == GameStatus::Counted {
+                counted_games += *count;
+                value * *count + bonuses.iter().map(|bonus| bonus.points).sum::<u32>()
+            } else {
+                0
+            };
+            total += points;
+
+            GamePoints {
+                game: game.clone(),
+                count: *count,
+                value,
+                bonuses,
+                points,
+                status,
+            }
+        })
+        .collect();
+
+    PointBreakdown { base: rules.base, games, total }
+}
+
+impl PointBreakdown {
+    // e.g. "1 base + Stardew Valley 2x2 + goal can be allsanity (2) + Clique (skipped) = 7"
+    pub fn describe(&self) -> String {
+        let mut parts = vec![format!("{} base", self.base)];
+        for game in &self.games {
+            match game.status {
+                GameStatus::Counted => {
+                    parts.push(format!("{} {}x{}", game.game, game.value, game.count));
+                    parts.extend(game.bonuses.iter().map(|bonus| format!("{} ({})", bonus.reason, bonus.points)));
+                }
+                GameStatus::Skipped => parts.push(format!("{} (skipped)", game.game)),
+                GameStatus::OverCap => parts.push(format!("{} (over the cap)", game.game)),
+            }
+        }
+
+        format!("{} = {}", parts.join(" + "), self.total)
+    }
+}
+
+// the point breakdown of every slot, for checking how a slot was scored
+pub fn write_points(path: &Path, points: Vec<Value>) {
+    if let Err(err) = File::create(path).and_then(|mut writer| writer.write_all(Value::Array(points).to_string().as_bytes())) {
+        println!("Failed to write to points file: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust2::YamlLoader;
+
+    use super::*;
+
+    fn options(yaml: &str) -> LinkedHashMap<Yaml, Yaml> {
+        YamlLoader::load_from_str(yaml).unwrap().remove(0).into_hash().unwrap()
+    }
+
+    // the modifiers of the example in the readme
+    fn rules() -> PointRules {
+        let modifier = |game: &str, option: &str, values: &[&str], at_least: Option<i64>, points: u32| PointModifier {
+            game: game.to_string(),
+            option: option.to_string(),
+            values: values.iter().map(|value| value.to_string()).collect(),
+            at_least,
+            points,
+        };
+
+        PointRules {
+            modifiers: vec![
+                modifier("Stardew Valley", "goal", &["allsanity"], None, 2),
+                modifier("Archipela-Go", "maximum_distance", &[], Some(5001), 1),
+                modifier("Archipela-Go", "maximum_distance", &[], Some(10001), 1),
+            ],
+            ..PointRules::default()
+        }
+    }
+
+    fn games(games: &[(&str, u32)]) -> Vec<(String, u32, Vec<Note>)> {
+        games.iter().map(|(game, count)| (game.to_string(), *count, vec![])).collect()
+    }
+
+    #[test]
+    fn bonus_only_counts_for_its_own_entry() {
+        let rules = rules();
+        let bonuses = option_bonuses(&options("goal: allsanity"), "Stardew Valley", 0, &rules);
+        let points = score(&games(&[("Stardew Valley", 1), ("A Short Hike", 1), ("Stardew Valley", 1)]), &bonuses, &rules);
+
+        assert_eq!(points.games[0].bonuses, bonuses);
+        assert!(points.games[2].bonuses.is_empty());
+        assert_eq!(points.total, 8);
+    }
+
+    #[test]
+    fn bonuses_of_aggregated_copies_add_up() {
+        let rules = rules();
+        let mut bonuses = option_bonuses(&options("goal: allsanity"), "Stardew Valley", 0, &rules);
+        bonuses.extend(option_bonuses(&options("goal: {allsanity: 1, perfection: 1}"), "Stardew Valley", 0, &rules));
+        let points = score(&games(&[("Stardew Valley", 2)]), &bonuses, &rules);
+
+        assert_eq!(points.total, 1 + 2 * 2 + 2 + 2);
+    }
+
+    #[test]
+    fn skipped_and_over_cap_games_do_not_score() {
+        let rules = rules();
+        let points = score(&games(&[("Clique", 1), ("A Short Hike", 5), ("Hollow Knight", 3), ("TUNIC", 1)]), &[], &rules);
+        let statuses: Vec<_> = points.games.iter().map(|game| game.status).collect();
+
+        assert_eq!(statuses, [GameStatus::Skipped, GameStatus::Counted, GameStatus::Counted, GameStatus::OverCap]);
+        assert_eq!(points.total, 1 + 5 + 3);
+    }
+
+    #[test]
+    fn bonuses_of_uncounted_games_do_not_score() {
+        let rules = PointRules { cap: 1, ..rules() };
+        let bonuses = option_bonuses(&options("goal: allsanity"), "Stardew Valley", 1, &rules);
+        let points = score(&games(&[("A Short Hike", 1), ("Stardew Valley", 1)]), &bonuses, &rules);
+
+        assert_eq!(points.games[1].status, GameStatus::OverCap);
+        assert_eq!(points.total, 2);
+    }
+
+    #[test]
+    fn option_bonuses_need_a_positive_weight() {
+        let rules = rules();
+
+        assert_eq!(option_bonuses(&options("goal: {allsanity: 1, perfection: 0}"), "Stardew Valley", 0, &rules).len(), 1);
+        assert!(option_bonuses(&options("goal: {allsanity: 0, perfection: 1}"), "Stardew Valley", 0, &rules).is_empty());
+        assert!(option_bonuses(&options("goal: perfection"), "Stardew Valley", 0, &rules).is_empty());
+        assert!(option_bonuses(&options("goal: allsanity"), "A Short Hike", 0, &rules).is_empty());
+    }
+
+    #[test]
+    fn option_bonuses_check_minimums() {
+        let rules = rules();
+        let reasons = |yaml: &str| -> Vec<String> {
+            option_bonuses(&options(yaml), "Archipela-Go", 3, &rules)
+                .into_iter()
+                .inspect(|bonus| assert_eq!(bonus.entry, 3))
+                .map(|bonus| bonus.reason)
+                .collect()
+        };
+
+        assert!(reasons("maximum_distance: 5000").is_empty());
+        assert_eq!(reasons("maximum_distance: 6000"), ["maximum_distance can be at least 5001"]);
+        assert_eq!(
+            reasons("maximum_distance: {2000: 1, 12000: 1}"),
+            ["maximum_distance can be at least 5001", "maximum_distance can be at least 10001"]
+        );
+    }
+}
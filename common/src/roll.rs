@@ -0,0 +1,111 @@
+use rand::Rng;
+use rand_distr::{Distribution, Triangular, WeightedIndex};
+use yaml_rust2::Yaml;
+
+use crate::{
+    util::as_i64,
+    warning::{WarningKind, Warnings},
+};
+
+// options that take a dict or list rather than a weighted choice, Archipelago never rolls these
+const UNWEIGHTED_OPTIONS: [&str; 14] = [
+    "start_inventory",
+    "start_inventory_from_pool",
+    "start_hints",
+    "start_location_hints",
+    "exclude_locations",
+    "priority_locations",
+    "local_items",
+    "non_local_items",
+    "item_links",
+    "plando_items",
+    "plando_connections",
+    "plando_texts",
+    "item_name_groups",
+    "triggers",
+];
+
+// forms that depend on the range or choices of the option, which only Archipelago knows
+const UNRESOLVABLE_RANDOM: [&str; 4] = ["random", "random-low", "random-middle", "random-high"];
+
+// rolls every weighted option of the game the same way Archipelago would, so the dist yaml contains the final settings
+pub fn roll_all_options<R: Rng>(doc: &mut Yaml, game: &Yaml, warnings: &mut Warnings, rng: &mut R) {
+    let game_name = game.as_str();
+
+    let Some(game_hash) = doc.as_mut_hash().and_then(|hash| hash.get_mut(game)).and_then(Yaml::as_mut_hash) else {
+        return;
+    };
+
+    for (option, value) in game_hash.iter_mut() {
+        let Some(option) = option.as_str() else {
+            continue;
+        };
+
+        if UNWEIGHTED_OPTIONS.contains(&option) {
+            continue;
+        }
+
+        if let Some(weights) = value.as_hash() {
+            // dicts of counts or weights such as trap_weights look the same as a weighted option, but their keys are names rather than values
+            if weights.is_empty() || !weights.iter().all(|(choice, weight)| is_plain_value(choice) && as_i64(weight).is_some()) {
+                continue;
+            }
+
+            let choices: Vec<_> = weights
+                .iter()
+                .filter_map(|(choice, weight)| as_i64(weight).filter(|weight| *weight > 0).map(|weight| (choice, weight)))
+                .collect();
+            if choices.is_empty() {
+                warnings.push(WarningKind::OptionRoll, game_name, format!("{option} has no value with a positive weight"));
+                continue;
+            }
+
+            let dist = WeightedIndex::new(choices.iter().map(|(_, weight)| weight)).expect("Weights should be positive");
+            *value = choices[dist.sample(rng)].0.clone();
+        }
+
+        if let Some(str) = value.as_str() {
+            if let Some(rolled) = roll_random_range(str, rng) {
+                *value = Yaml::Integer(rolled);
+            } else if UNRESOLVABLE_RANDOM.contains(&str) {
+                warnings.push(WarningKind::OptionRoll, game_name, format!("{option} rolled '{str}', which is left for Archipelago to resolve"));
+            }
+        }
+    }
+}
+
+// the values Archipelago accepts for a weighted option: numbers, booleans and lowercase choice names like `normal` or `random-range-1-10`
+fn is_plain_value(value: &Yaml) -> bool {
+    match value {
+        Yaml::Integer(_) | Yaml::Real(_) | Yaml::Boolean(_) => true,
+        Yaml::String(str) => !str.is_empty() && str.chars().all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_' || char == '-'),
+        _ => false,
+    }
+}
+
+// resolves random-range-X-Y, random-range-low-X-Y, random-range-middle-X-Y and random-range-high-X-Y
+fn roll_random_range<R: Rng>(str: &str, rng: &mut R) -> Option<i64> {
+    let range = str.strip_prefix("random-range-")?;
+    let (mode, range) = ["low", "middle", "high"]
+        .iter()
+        .find_map(|mode| range.strip_prefix(mode).and_then(|range| range.strip_prefix('-')).map(|range| (Some(*mode), range)))
+        .unwrap_or((None, range));
+
+    let (min, max) = range.split_once('-')?;
+    let (min, max): (i64, i64) = (min.parse().ok()?, max.parse().ok()?);
+    let (min, max) = (min.min(max), min.max(max));
+
+    if min == max {
+        return Some(min);
+    }
+
+    let mode = match mode {
+        None => return Some(rng.gen_range(min..=max)),
+        Some("low") => min as f64,
+        Some("high") => max as f64,
+        _ => (min + max) as f64 / 2.0,
+    };
+
+    let dist = Triangular::new(min as f64, max as f64, mode).ok()?;
+    Some(dist.sample(rng).round() as i64)
+}
@@ -1,9 +1,16 @@
 use hashlink::LinkedHashMap;
 use phf::phf_map;
+use rand::Rng;
 use serde_json::Value;
 use yaml_rust2::Yaml;
 
-use crate::util::{as_i64, resolve_weighted_option};
+use crate::{
+    roll::roll_all_options,
+    triggers::{Trigger, parse_triggers, report_triggers, trigger_changes, triggers_for_game},
+    util::{as_i64, resolve_weighted_option},
+    warning::{WarningKind, Warnings},
+    write::Note,
+};
 
 const ARCHIPELA_GO_DISTANCES: phf::Map<&'static str, i64> = phf_map!(
     "2k" => 2000,
@@ -41,195 +48,142 @@ const STS_SUPPORTED_CHARACTERS: [&str; 14] = [
     "Hexaghost",
 ];
 
-pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
-    let mut notes = vec![];
+pub fn handle_special<R: Rng>(doc: &mut Yaml, game: &Yaml, roll_options: bool, warnings: &mut Warnings, rng: &mut R) -> Vec<Note> {
+    migrate_triggers(doc, game, warnings);
 
-    let hash = if let Some(hash) = doc.as_mut_hash() {
-        hash
-    } else {
-        return notes;
-    };
+    if let Some(game_hash) = doc.as_mut_hash().and_then(|hash| hash.get_mut(game)).and_then(Yaml::as_mut_hash) {
+        migrate_game_options(game_hash, game);
+    }
 
-    if hash.contains_key(&Yaml::from_str("triggers")) {
-        println!("'{name}.yaml' contains triggers");
+    // options are rolled after migrating them, so a migration never rewrites a value that was already rolled
+    if roll_options {
+        roll_all_options(doc, game, warnings, rng);
     }
 
-    let game_hash = if let Some(game_options) = hash.get_mut(game) {
-        if let Some(game_hash) = game_options.as_mut_hash() {
-            game_hash
-        } else {
-            return notes;
-        }
-    } else {
-        return notes;
-    };
+    let triggers = parse_triggers(doc, game, warnings);
+    report_triggers(&triggers, doc, game, warnings);
+    let triggers = triggers_for_game(&triggers, game);
 
-    if game_hash.contains_key(&Yaml::from_str("triggers")) {
-        println!("'{name}.yaml' contains triggers");
+    if let Some(game_hash) = doc.as_mut_hash().and_then(|hash| hash.get_mut(game)).and_then(Yaml::as_mut_hash) {
+        handle_game_options(game_hash, game, &triggers, warnings, rng)
+    } else {
+        vec![]
     }
+}
+
+// the notes for the options of one game, once they are migrated and rolled
+fn handle_game_options<R: Rng>(game_hash: &mut LinkedHashMap<Yaml, Yaml>, game: &Yaml, triggers: &[Trigger], warnings: &mut Warnings, rng: &mut R) -> Vec<Note> {
+    let mut notes = vec![];
+    let game_name = game.as_str();
 
     match game.as_str() {
         Some("OpenRCT2") => {
-            if let Some(mut awards) = game_hash.remove(&Yaml::from_str("awards")) {
-                move_option_weight(&mut awards, "false", "none");
-                move_option_weight(&mut awards, "true", "all");
-                game_hash.insert(Yaml::from_str("selected_awards"), awards);
-            }
-            if let Some(food_poisioning_traps) = game_hash.remove(&Yaml::from_str("food_poisioning_traps")) {
-                game_hash.insert(Yaml::from_str("food_poisoning_traps"), food_poisioning_traps);
-            }
-
-            println!(
-                "'{name}.yaml' contains an OpenRCT2 with scenario: {}",
-                get_value_or_default(game_hash, "scenario", "archipelago_madness_vanilla")
+            warnings.push(
+                WarningKind::GameAdvisory,
+                game_name,
+                format!("contains an OpenRCT2 with scenario: {}", get_value_or_default(game_hash, "scenario", "archipelago_madness_vanilla")),
             );
         }
         Some("Stardew Valley") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "perfection", "random");
-            }
-
             if option_can_be(game_hash, "goal", &Yaml::from_str("random"), &Yaml::from_str("allsanity")) {
-                println!("'{name}.yaml' has goal 'allsanity'");
-            }
-
-            if let Some(entrance_randomization) = game_hash.get_mut(&Yaml::from_str("entrance_randomization")) {
-                move_option_weight(entrance_randomization, "chaos", "disabled");
-            }
-
-            if let Some(hatsanity) = game_hash.get_mut(&Yaml::from_str("hatsanity")).and_then(|yaml| yaml.as_mut_vec()) {
-                hatsanity.retain(|yaml| yaml.as_str().is_none_or(|str| str != "Near Perfection" && str != "Post Perfection"));
+                warnings.push(WarningKind::GameAdvisory, game_name, "has goal 'allsanity'");
             }
-
-            change_option_name(game_hash, "trap_items", "trap_difficulty");
-            push_value_or_default(&mut notes, game_hash, "mods", "[]");
-            push_value_or_default(&mut notes, game_hash, "custom_logic", "[]");
+            push_value_or_default(&mut notes, game_hash, triggers, "mods", "[]");
+            push_value_or_default(&mut notes, game_hash, triggers, "custom_logic", "[]");
         }
         Some("osu!") => {
             game_hash.insert(Yaml::from_str("explicit_lyrics"), Yaml::Boolean(false));
 
-            if let Some(minimum_difficulty) = game_hash.remove(&Yaml::from_str("minimum_difficulty")) {
-                game_hash.insert(Yaml::from_str("minimum_difficulty_standard"), minimum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("minimum_difficulty_catch"), minimum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("minimum_difficulty_taiko"), minimum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("minimum_difficulty_4k"), minimum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("minimum_difficulty_7k"), minimum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("minimum_difficulty_other_keys"), minimum_difficulty);
-            }
-
-            if let Some(maximum_difficulty) = game_hash.remove(&Yaml::from_str("maximum_difficulty")) {
-                game_hash.insert(Yaml::from_str("maximum_difficulty_standard"), maximum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("maximum_difficulty_catch"), maximum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("maximum_difficulty_taiko"), maximum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("maximum_difficulty_4k"), maximum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("maximum_difficulty_7k"), maximum_difficulty.clone());
-                game_hash.insert(Yaml::from_str("maximum_difficulty_other_keys"), maximum_difficulty);
-            }
-
-            push_value_or_default(&mut notes, game_hash, "minimum_grade", "off");
-            push_value_or_default(&mut notes, game_hash, "disable_difficulty_reduction", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "minimum_grade", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "disable_difficulty_reduction", "false");
             if option_can_be(game_hash, "exclude_standard", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                notes.push(format!(
-                    "standard: {}-{}",
+                notes.push(Note::new("standard", format!("{}-{}",
                     get_value_or_default(game_hash, "minimum_difficulty_standard", "0"),
                     get_value_or_default(game_hash, "maximum_difficulty_standard", "1000")
-                ));
+                )));
             }
             if option_can_be(game_hash, "exclude_catch", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                notes.push(format!(
-                    "catch: {}-{}",
+                notes.push(Note::new("catch", format!("{}-{}",
                     get_value_or_default(game_hash, "minimum_difficulty_catch", "0"),
                     get_value_or_default(game_hash, "maximum_difficulty_catch", "1000")
-                ));
+                )));
             }
             if option_can_be(game_hash, "exclude_taiko", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                notes.push(format!(
-                    "taiko: {}-{}",
+                notes.push(Note::new("taiko", format!("{}-{}",
                     get_value_or_default(game_hash, "minimum_difficulty_taiko", "0"),
                     get_value_or_default(game_hash, "maximum_difficulty_taiko", "1000")
-                ));
+                )));
             }
             if option_can_be(game_hash, "exclude_4k", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                notes.push(format!(
-                    "4k: {}-{}",
+                notes.push(Note::new("4k", format!("{}-{}",
                     get_value_or_default(game_hash, "minimum_difficulty_4k", "0"),
                     get_value_or_default(game_hash, "maximum_difficulty_4k", "1000")
-                ));
+                )));
             }
             if option_can_be(game_hash, "exclude_7k", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                notes.push(format!(
-                    "7k: {}-{}",
+                notes.push(Note::new("7k", format!("{}-{}",
                     get_value_or_default(game_hash, "minimum_difficulty_7k", "0"),
                     get_value_or_default(game_hash, "maximum_difficulty_7k", "1000")
-                ));
+                )));
             }
             if option_can_be(game_hash, "exclude_other_keys", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                notes.push(format!(
-                    "other_keys: {}-{}",
+                notes.push(Note::new("other_keys", format!("{}-{}",
                     get_value_or_default(game_hash, "minimum_difficulty_other_keys", "0"),
                     get_value_or_default(game_hash, "maximum_difficulty_other_keys", "1000")
-                ));
+                )));
             }
         }
         Some("Keymaster's Keep") => {
             if option_can_be(game_hash, "include_adult_only_or_unrated_games", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
-                println!("'{name}.yaml' may have adult only or unrated games in Keymaster's Keep");
+                warnings.push(WarningKind::GameAdvisory, game_name, "may have adult only or unrated games in Keymaster's Keep");
             }
 
             game_hash.insert(Yaml::from_str("include_adult_only_or_unrated_games"), Yaml::Boolean(false));
         }
         Some("Overcooked! 2") => {
-            if let Some(star_threshold_scale) = game_hash.get_mut(&Yaml::from_str("star_threshold_scale")) {
-                move_option_weight_matches(star_threshold_scale, |yaml| as_i64(yaml).is_some_and(|v| v >= 90), "random-range-50-90");
-            }
-
-            push_value_or_default(&mut notes, game_hash, "include_dlcs", "['Story', 'Seasonal']");
-            push_value_or_default(&mut notes, game_hash, "ramp_tricks", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "include_dlcs", "['Story', 'Seasonal']");
+            push_value_or_default(&mut notes, game_hash, triggers, "ramp_tricks", "false");
         }
-        Some("Blasphemous") => push_value_or_default(&mut notes, game_hash, "difficulty", "normal"),
-        Some("Bomb Rush Cyberfunk") => push_value_or_default(&mut notes, game_hash, "logic", "glitchless"),
-        Some("Celeste 64") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "Standard"),
+        Some("Blasphemous") => push_value_or_default(&mut notes, game_hash, triggers, "difficulty", "normal"),
+        Some("Bomb Rush Cyberfunk") => push_value_or_default(&mut notes, game_hash, triggers, "logic", "glitchless"),
+        Some("Celeste 64") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "Standard"),
         Some("Dark Souls II") => {
-            push_value_or_default(&mut notes, game_hash, "game_version", "sotfs");
-            push_value_or_default(&mut notes, game_hash, "old_iron_king_dlc", "false");
-            push_value_or_default(&mut notes, game_hash, "ivory_king_dlc", "false");
-            push_value_or_default(&mut notes, game_hash, "sunken_king_dlc", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "game_version", "sotfs");
+            push_value_or_default(&mut notes, game_hash, triggers, "old_iron_king_dlc", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "ivory_king_dlc", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "sunken_king_dlc", "false");
         }
-        Some("Dark Souls III") => push_value_or_default(&mut notes, game_hash, "enable_dlc", "false"),
+        Some("Dark Souls III") => push_value_or_default(&mut notes, game_hash, triggers, "enable_dlc", "false"),
         Some("Grim Dawn") => {
-            push_value_or_default(&mut notes, game_hash, "dlc_aom", "false");
-            push_value_or_default(&mut notes, game_hash, "dlc_fg", "false");
-        }
-        Some("DLCQuest") => push_value_or_default(&mut notes, game_hash, "double_jump_glitch", "none"),
-        Some("DOOM 1993") => push_value_or_default(&mut notes, game_hash, "pro", "false"),
-        Some("DOOM II") => push_value_or_default(&mut notes, game_hash, "pro", "false"),
-        Some("Final Fantasy") => push_value_or_default(&mut notes, game_hash, "permalink", "N/A"),
-        Some("Final Fantasy Mystic Quest") => push_value_or_default(&mut notes, game_hash, "logic", "standard"),
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_aom", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_fg", "false");
+        }
+        Some("DLCQuest") => push_value_or_default(&mut notes, game_hash, triggers, "double_jump_glitch", "none"),
+        Some("DOOM 1993") => push_value_or_default(&mut notes, game_hash, triggers, "pro", "false"),
+        Some("DOOM II") => push_value_or_default(&mut notes, game_hash, triggers, "pro", "false"),
+        Some("Final Fantasy") => push_value_or_default(&mut notes, game_hash, triggers, "permalink", "N/A"),
+        Some("Final Fantasy Mystic Quest") => push_value_or_default(&mut notes, game_hash, triggers, "logic", "standard"),
         Some("Final Fantasy 12 Open World") => {
-            if let Some(character_progression_scaling) = game_hash.remove(&Yaml::from_str("character_progression_scaling")) {
-                game_hash.insert(Yaml::from_str("difficulty_progressive_scaling"), character_progression_scaling);
-            }
-
-            push_value_or_default(&mut notes, game_hash, "character_progression_scaling", "true");
+            push_value_or_default(&mut notes, game_hash, triggers, "character_progression_scaling", "true");
         }
         Some("A Hat in Time") => {
-            push_value_or_default(&mut notes, game_hash, "LogicDifficulty", "normal");
+            push_value_or_default(&mut notes, game_hash, triggers, "LogicDifficulty", "normal");
 
             if option_can_be(game_hash, "EnableDeathWish", &Yaml::Boolean(false), &Yaml::Boolean(true)) && !option_can_be(game_hash, "EnableDLC1", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
-                notes.push(String::from("EnableDLC1: deathwishonly"));
+                notes.push(Note::new("EnableDLC1", "deathwishonly"));
             } else {
-                push_value_or_default(&mut notes, game_hash, "EnableDLC1", "false");
+                push_value_or_default(&mut notes, game_hash, triggers, "EnableDLC1", "false");
             }
 
-            push_value_or_default(&mut notes, game_hash, "EnableDLC2", "false");
-            push_value_or_default(&mut notes, game_hash, "DWEnableBonus", "false");
-            push_value_or_default(&mut notes, game_hash, "DWExcludeAnnoyingContracts", "true");
+            push_value_or_default(&mut notes, game_hash, triggers, "EnableDLC2", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "DWEnableBonus", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "DWExcludeAnnoyingContracts", "true");
 
             if option_can_be(game_hash, "DWShuffle", &Yaml::Boolean(false), &Yaml::Boolean(true)) && option_can_be(game_hash, "DWEnableBonus", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
-                push_value_or_default(&mut notes, game_hash, "DWExcludeAnnoyingBonuses", "true");
+                push_value_or_default(&mut notes, game_hash, triggers, "DWExcludeAnnoyingBonuses", "true");
             }
         }
-        Some("Heretic") => push_value_or_default(&mut notes, game_hash, "pro", "false"),
+        Some("Heretic") => push_value_or_default(&mut notes, game_hash, triggers, "pro", "false"),
         Some("Hollow Knight") => {
             let skips: Vec<_> = [
                 "PreciseMovement",
@@ -254,40 +208,27 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             .collect();
 
             if skips.is_empty() {
-                notes.push(String::from("Skips: none"));
+                notes.push(Note::new("Skips", "none"));
             } else {
-                notes.push(format!("Skips: [{}]", skips.join(", ")));
-            }
-        }
-        Some("Kingdom Hearts 2") => push_value_or_default(&mut notes, game_hash, "FightLogic", "normal"),
-        Some("Kingdom Hearts") => {
-            rename_true_false(game_hash, "cups", "cups", "off");
-
-            if let Some(level_checks) = game_hash.get_mut(&Yaml::from_str("level_checks")) {
-                move_option_weight(level_checks, "100", "99");
-            }
-
-            if let Some(force_stats_on_levels) = game_hash.get_mut(&Yaml::from_str("force_stats_on_levels")) {
-                move_option_weight(force_stats_on_levels, "1", "2");
+                notes.push(Note::new("Skips", format!("[{}]", skips.join(", "))));
             }
         }
-        Some("A Link to the Past") => push_value_or_default(&mut notes, game_hash, "glitches_required", "no_glitches"),
-        Some("Links Awakening DX") => push_value_or_default(&mut notes, game_hash, "logic", "normal"),
+        Some("Kingdom Hearts 2") => push_value_or_default(&mut notes, game_hash, triggers, "FightLogic", "normal"),
+        Some("A Link to the Past") => push_value_or_default(&mut notes, game_hash, triggers, "glitches_required", "no_glitches"),
+        Some("Links Awakening DX") => push_value_or_default(&mut notes, game_hash, triggers, "logic", "normal"),
         Some("Mario & Luigi Superstar Saga") => {
-            game_hash.remove(&Yaml::from_str("harhall_pants"));
-            push_value_or_default(&mut notes, game_hash, "difficult_logic", "FALSE");
+            push_value_or_default(&mut notes, game_hash, triggers, "difficult_logic", "FALSE");
         }
-        Some("The Messenger") => push_value_or_default(&mut notes, game_hash, "logic_level", "normal"),
+        Some("The Messenger") => push_value_or_default(&mut notes, game_hash, triggers, "logic_level", "normal"),
         Some("Muse Dash") => {
-            push_value_or_default(&mut notes, game_hash, "dlc_packs", "[]");
-            game_hash.remove(&Yaml::from_str("available_trap_types"));
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_packs", "[]");
         }
         Some("Ocarina of Time") => {
-            push_value_or_default(&mut notes, game_hash, "logic_rules", "glitchless");
-            push_value_or_default(&mut notes, game_hash, "logic_tricks", "[]")
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_rules", "glitchless");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_tricks", "[]")
         }
         Some("Pokemon Red and Blue") => {
-            resolve_weighted_option(game_hash, "game_version");
+            resolve_weighted_option(game_hash, "game_version", rng);
 
             if option_can_be(game_hash, "game_version", &Yaml::from_str("random"), &Yaml::from_str("random")) {
                 let mut new_hash = LinkedHashMap::new();
@@ -296,20 +237,20 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 game_hash.insert(Yaml::from_str("game_version"), Yaml::Hash(new_hash));
             }
 
-            resolve_weighted_option(game_hash, "game_version");
+            resolve_weighted_option(game_hash, "game_version", rng);
 
-            push_value_or_default(&mut notes, game_hash, "game_version", "N/A");
+            push_value_or_default(&mut notes, game_hash, triggers, "game_version", "N/A");
 
             if option_can_be_other_than(game_hash, "trainer_name", &Yaml::from_str("choose_in_game"), &Yaml::from_str("choose_in_game")) {
-                println!("'{name}.yaml' contains a chosen trainer name");
+                warnings.push(WarningKind::GameAdvisory, game_name, "contains a chosen trainer name");
             }
         }
         Some("Risk of Rain 2") => {
-            push_value_or_default(&mut notes, game_hash, "dlc_sotv", "false");
-            push_value_or_default(&mut notes, game_hash, "dlc_sots", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_sotv", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_sots", "false");
         }
         Some("A Short Hike") => {
-            resolve_weighted_option(game_hash, "golden_feathers");
+            resolve_weighted_option(game_hash, "golden_feathers", rng);
 
             let mut golden_feather_progression = get_value_or_default(game_hash, "golden_feather_progression", "normal");
 
@@ -329,49 +270,15 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 golden_feather_progression = String::from("random");
             }
 
-            notes.push(format!("golden_feather_progression: {golden_feather_progression}"));
+            notes.push(Note::new("golden_feather_progression", golden_feather_progression.to_string()));
         }
-        Some("SMZ3") => push_value_or_default(&mut notes, game_hash, "sm_logic", "normal"),
+        Some("SMZ3") => push_value_or_default(&mut notes, game_hash, triggers, "sm_logic", "normal"),
         Some("Sonic Adventure 2 Battle") => {
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "standard");
-            push_value_or_default(&mut notes, game_hash, "chao_karate_difficulty", "none");
-            push_value_or_default(&mut notes, game_hash, "sadx_music", "sa2b");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "standard");
+            push_value_or_default(&mut notes, game_hash, triggers, "chao_karate_difficulty", "none");
+            push_value_or_default(&mut notes, game_hash, triggers, "sadx_music", "sa2b");
         }
         Some("Starcraft 2") => {
-            if let Some(mission_order) = game_hash.get_mut(&Yaml::from_str("mission_order")) {
-                move_option_weight(mission_order, "tiny_grid", "grid");
-                move_option_weight(mission_order, "mini_grid", "grid");
-                move_option_weight(mission_order, "medium_grid", "grid");
-                move_option_weight(mission_order, "mini_gauntlet", "gauntlet");
-            }
-
-            if let Some(kerrigan_presence) = game_hash.get_mut(&Yaml::from_str("kerrigan_presence")) {
-                move_option_weight(kerrigan_presence, "not_present_and_no_passives", "not_present");
-                move_option_weight(kerrigan_presence, "kerrigan_max_passive_abilities", "0");
-            }
-
-            if let Some(spear_of_adun_presence) = game_hash.get_mut(&Yaml::from_str("spear_of_adun_presence")) {
-                move_option_weight(spear_of_adun_presence, "lotv_protoss", "vanilla");
-            }
-
-            rename_true_false(game_hash, "grant_story_tech", "grant", "no_grant");
-
-            if let Some(vanilla_locations) = game_hash.get_mut(&Yaml::from_str("vanilla_locations")) {
-                move_option_weight(vanilla_locations, "resources", "filler");
-            }
-
-            if let Some(extra_locations) = game_hash.get_mut(&Yaml::from_str("extra_locations")) {
-                move_option_weight(extra_locations, "resources", "filler");
-            }
-
-            if let Some(challenge_locations) = game_hash.get_mut(&Yaml::from_str("challenge_locations")) {
-                move_option_weight(challenge_locations, "resources", "filler");
-            }
-
-            if let Some(mastery_locations) = game_hash.get_mut(&Yaml::from_str("mastery_locations")) {
-                move_option_weight(mastery_locations, "resources", "filler");
-            }
-
             let key = Yaml::from_str("enabled_campaigns");
             if !game_hash.contains_key(&key) {
                 game_hash.insert(
@@ -394,51 +301,42 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 );
             }
 
-            if let Some(grid_two_start_positions) = game_hash.remove(&Yaml::from_str("grid_two_start_positions")) {
-                game_hash.insert(Yaml::from_str("two_start_positions"), grid_two_start_positions);
-            }
-
-            push_value_or_default(&mut notes, game_hash, "required_tactics", "standard");
+            push_value_or_default(&mut notes, game_hash, triggers, "required_tactics", "standard");
         }
         Some("Super Metroid") => {
-            push_value_or_default(&mut notes, game_hash, "preset", "regular");
-            push_value_or_default(&mut notes, game_hash, "max_difficulty", "hardcore");
+            push_value_or_default(&mut notes, game_hash, triggers, "preset", "regular");
+            push_value_or_default(&mut notes, game_hash, triggers, "max_difficulty", "hardcore");
             if option_can_be(game_hash, "preset", &Yaml::from_str("regular"), &Yaml::from_str("varia_custom")) {
-                push_value_or_default(&mut notes, game_hash, "varia_custom_preset", "N/A");
+                push_value_or_default(&mut notes, game_hash, triggers, "varia_custom_preset", "N/A");
             }
         }
         Some("Terraria") => {
             if option_can_be(game_hash, "goal", &Yaml::Null, &Yaml::from_str("boss_rush")) {
-                notes.push(String::from("calamity: true"));
+                notes.push(Note::new("calamity", "true"));
             } else {
-                push_value_or_default(&mut notes, game_hash, "calamity", "false")
+                push_value_or_default(&mut notes, game_hash, triggers, "calamity", "false")
             };
         }
         Some("TUNIC") => {
-            game_hash.remove(&Yaml::from_str("logic_rules"));
-            game_hash.remove(&Yaml::from_str("fixed_shop"));
-
-            push_value_or_default(&mut notes, game_hash, "combat_logic", "off");
-            push_value_or_default(&mut notes, game_hash, "lanternless", "false");
-            push_value_or_default(&mut notes, game_hash, "maskless", "false");
-            push_value_or_default(&mut notes, game_hash, "laurels_zips", "false");
-            push_value_or_default(&mut notes, game_hash, "ice_grappling", "off");
-            push_value_or_default(&mut notes, game_hash, "ladder_storage", "off");
-            push_value_or_default(&mut notes, game_hash, "ladder_storage_without_items", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "combat_logic", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "lanternless", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "maskless", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "laurels_zips", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "ice_grappling", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "ladder_storage", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "ladder_storage_without_items", "off");
         }
         Some("The Wind Waker") => {
-            push_value_or_default(&mut notes, game_hash, "logic_obscurity", "none");
-            push_value_or_default(&mut notes, game_hash, "logic_precision", "none");
-            push_value_or_default(&mut notes, game_hash, "enable_tuner_logic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_obscurity", "none");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_precision", "none");
+            push_value_or_default(&mut notes, game_hash, triggers, "enable_tuner_logic", "false");
         }
         Some("Yoshi's Island") => {
-            push_value_or_default(&mut notes, game_hash, "stage_logic", "strict");
-            push_value_or_default(&mut notes, game_hash, "item_logic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "stage_logic", "strict");
+            push_value_or_default(&mut notes, game_hash, triggers, "item_logic", "false");
         }
         Some("A Link Between Worlds") => {
-            rename_true_false(game_hash, "super_items", "shuffled", "off");
-
-            resolve_weighted_option(game_hash, "keysy");
+            resolve_weighted_option(game_hash, "keysy", rng);
             if let Some(keysy) = game_hash.remove(&Yaml::from_str("keysy")) {
                 match keysy.as_str() {
                     Some("small") => {
@@ -455,38 +353,24 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 }
             }
 
-            push_value_or_default(&mut notes, game_hash, "logic_mode", "normal");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_mode", "normal");
         }
         Some("Banjo-Tooie") => {
-            if let Some(randomize_world_entrance_loading_zone) = game_hash.remove(&Yaml::from_str("randomize_world_entrance_loading_zone")) {
-                game_hash.insert(Yaml::from_str("randomize_world_entrance_loading_zones"), randomize_world_entrance_loading_zone);
-            }
-
-            if let Some(randomize_boss_loading_zone) = game_hash.remove(&Yaml::from_str("randomize_boss_loading_zone")) {
-                game_hash.insert(Yaml::from_str("randomize_boss_loading_zones"), randomize_boss_loading_zone);
-            }
-
-            push_value_or_default(&mut notes, game_hash, "logic_type", "intended");
-            change_option_name(game_hash, "game_length", "world_requirements");
-            if let Some(open_silos) = game_hash.get_mut(&Yaml::from_str("open_silos")) {
-                move_option_weight(open_silos, "none", "1");
-                move_option_weight(open_silos, "one", "2");
-                move_option_weight(open_silos, "all", "7");
-            }
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_type", "intended");
         }
-        Some("Duke Nukem 3D") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "medium"),
-        Some("The Legend of Zelda - Oracle of Ages") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "casual"),
+        Some("Duke Nukem 3D") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "medium"),
+        Some("The Legend of Zelda - Oracle of Ages") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "casual"),
         Some("The Legend of Zelda - Oracle of Seasons") => {
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "casual");
-            push_value_or_default(&mut notes, game_hash, "cross_items", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "casual");
+            push_value_or_default(&mut notes, game_hash, triggers, "cross_items", "false");
         }
         Some("Ori and the Blind Forest") => {
-            resolve_weighted_option(game_hash, "goal");
+            resolve_weighted_option(game_hash, "goal", rng);
             if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
                 *goal = Yaml::Array(goal.as_str().and_then(|str| ORI_GOAL_NAMES.get(str)).copied().map(Yaml::from_str).into_iter().collect());
             }
 
-            resolve_weighted_option(game_hash, "logic_difficulty");
+            resolve_weighted_option(game_hash, "logic_difficulty", rng);
             if let Some(yaml) = game_hash.get_mut(&Yaml::from_str("logic_difficulty"))
                 && yaml.as_str() == Some("glitched")
             {
@@ -508,22 +392,17 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 );
             }
 
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "casual");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "casual");
             push_value_or_default(
                 &mut notes,
                 game_hash,
+                triggers,
                 "logic_modifiers",
                 "[AirDash, ChargeFlameBurn, Lure, DamageBoost, GrenadeJump, Rekindle, TripleJump, DoubleBash, ChargeDash]",
             );
         }
         Some("Ori and the Will of the Wisps") => {
-            if let Some(spawn) = game_hash.get_mut(&Yaml::from_str("spawn")) {
-                move_option_weight(spawn, "marsh", "vanilla");
-            }
-            rename_true_false(game_hash, "door_rando", "coupled", "disabled");
-            game_hash.remove(&Yaml::from_str("regenerate_requirements"));
-
-            push_value_or_default(&mut notes, game_hash, "difficulty", "moki");
+            push_value_or_default(&mut notes, game_hash, triggers, "difficulty", "moki");
         }
         Some("Outer Wilds") => {
             game_hash.insert(Yaml::from_str("enable_hn2_mod"), Yaml::Boolean(false));
@@ -545,13 +424,13 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             .collect();
 
             if mods.is_empty() {
-                notes.push(String::from("Mods: none"));
+                notes.push(Note::new("Mods", "none"));
             } else {
-                notes.push(format!("Mods: [{}]", mods.join(", ")));
+                notes.push(Note::new("Mods", format!("[{}]", mods.join(", "))));
             }
         }
         Some("Pokemon FireRed and LeafGreen") => {
-            resolve_weighted_option(game_hash, "game_version");
+            resolve_weighted_option(game_hash, "game_version", rng);
 
             if option_can_be(game_hash, "game_version", &Yaml::from_str("random"), &Yaml::from_str("random")) {
                 let mut new_hash = LinkedHashMap::new();
@@ -560,31 +439,9 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 game_hash.insert(Yaml::from_str("game_version"), Yaml::Hash(new_hash));
             }
 
-            game_hash.remove(&Yaml::from_str("shop_prices"));
-
-            resolve_weighted_option(game_hash, "game_version");
-
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "elite_four", "champion");
-                move_option_weight(goal, "elite_four_rematch", "champion_rematch");
-            }
-
-            rename_true_false(game_hash, "trainersanity", "456", "0");
-            rename_true_false(game_hash, "provide_hints", "all", "off");
-            rename_true_false(game_hash, "flash_required", "required", "off");
-            rename_true_false(game_hash, "randomize_fly_destinations", "completely_random", "off");
-
-            if let Some(dungeon_entrance_shuffle) = game_hash.remove(&Yaml::from_str("dungeon_entrance_shuffle")) {
-                game_hash.insert(Yaml::from_str("shuffle_dungeons"), dungeon_entrance_shuffle);
-            }
-
-            if let Some(mut shuffle_ledge_jump) = game_hash.remove(&Yaml::from_str("shuffle_ledge_jump")) {
-                move_option_weight(&mut shuffle_ledge_jump, "false", "off");
-                move_option_weight(&mut shuffle_ledge_jump, "true", "full");
-                game_hash.insert(Yaml::from_str("shuffle_dropdowns"), shuffle_ledge_jump);
-            }
+            resolve_weighted_option(game_hash, "game_version", rng);
 
-            resolve_weighted_option(game_hash, "exp_modifier");
+            resolve_weighted_option(game_hash, "exp_modifier", rng);
 
             let game_options_key = Yaml::from_str("game_options");
             if let Some(game_options) = game_hash.get_mut(&game_options_key).and_then(|yaml| yaml.as_mut_hash()) {
@@ -604,32 +461,30 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 }
             }
 
-            push_value_or_default(&mut notes, game_hash, "game_version", "N/A");
-            push_value_or_default(&mut notes, game_hash, "evolutions_required", "[HM Requirement, Oak's Aides, Dexsanity]");
+            push_value_or_default(&mut notes, game_hash, triggers, "game_version", "N/A");
+            push_value_or_default(&mut notes, game_hash, triggers, "evolutions_required", "[HM Requirement, Oak's Aides, Dexsanity]");
             push_value_or_default(
                 &mut notes,
                 game_hash,
+                triggers,
                 "evolution_methods_required",
                 "[Level, Level Tyrogue, Level Wurmple, Evo Item, Evo & Held Item, Friendship]",
             );
         }
-        Some("Pseudoregalia") => push_value_or_default(&mut notes, game_hash, "logic_level", "normal"),
+        Some("Pseudoregalia") => push_value_or_default(&mut notes, game_hash, triggers, "logic_level", "normal"),
         Some("Rusted Moss") => {
-            push_value_or_default(&mut notes, game_hash, "damage_boost", "false");
-            push_value_or_default(&mut notes, game_hash, "grenade_boost", "false");
-            push_value_or_default(&mut notes, game_hash, "precise_movement", "false");
-            push_value_or_default(&mut notes, game_hash, "precise_grapple", "true");
-            push_value_or_default(&mut notes, game_hash, "bunny_hopping", "false");
-            push_value_or_default(&mut notes, game_hash, "hard_combat", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "damage_boost", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "grenade_boost", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "precise_movement", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "precise_grapple", "true");
+            push_value_or_default(&mut notes, game_hash, triggers, "bunny_hopping", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "hard_combat", "false");
         }
         Some("Slay the Spire") => {
-            if let Some(character) = game_hash.remove(&Yaml::from_str("character")) {
-                game_hash.insert(Yaml::from_str("characters"), character);
-            }
-            push_value_or_default(&mut notes, game_hash, "downfall", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "downfall", "false");
             let mut warn_modded = false;
             if option_can_be(game_hash, "use_advanced_characters", &Yaml::Boolean(false), &Yaml::Boolean(false)) {
-                push_value_or_default(&mut notes, game_hash, "characters", "[Ironclad]");
+                push_value_or_default(&mut notes, game_hash, triggers, "characters", "[Ironclad]");
                 if let Some(yaml) = game_hash.get(&Yaml::from_str("characters")) {
                     warn_modded = match yaml {
                         Yaml::Array(vec) => vec.iter().any(|yaml| yaml.as_str().is_some_and(|str| !STS_SUPPORTED_CHARACTERS.contains(&str))),
@@ -641,17 +496,17 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             if option_can_be(game_hash, "use_advanced_characters", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
                 if let Some(advanced_characters_hash) = game_hash.get(&Yaml::from_str("advanced_characters")).and_then(Yaml::as_hash) {
                     let characters = advanced_characters_hash.keys().map(to_string).collect::<Vec<_>>();
-                    notes.push(format!("characters: [{}]", characters.join(", ")));
+                    notes.push(Note::new("characters", format!("[{}]", characters.join(", "))));
                     if !warn_modded {
                         warn_modded = characters.iter().any(|str| !STS_SUPPORTED_CHARACTERS.contains(&str.as_str()));
                     }
                 } else {
-                    notes.push(String::from("characters: [Ironclad]"));
+                    notes.push(Note::new("characters", "[Ironclad]"));
                 }
             }
 
             if warn_modded {
-                println!("'{name}.yaml' contains custom characters for Slay the Spire");
+                warnings.push(WarningKind::GameAdvisory, game_name, "contains custom characters for Slay the Spire");
             }
 
             let ascension = Yaml::from_str("ascension");
@@ -660,81 +515,43 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             }
         }
         Some("Super Metroid Map Rando") => {
-            rename_true_false(game_hash, "transition_letters", "letters", "arrows");
-            push_value_or_default(&mut notes, game_hash, "preset", "hard");
-            println!("'{name}.yaml' contains a Super Metroid Map Rando");
+            push_value_or_default(&mut notes, game_hash, triggers, "preset", "hard");
+            warnings.push(WarningKind::GameAdvisory, game_name, "contains a Super Metroid Map Rando");
         }
         Some("Sonic Adventure DX") => {
-            println!("'{name}.yaml' contains a Sonic Adventure DX",);
-
-            rename_true_false(game_hash, "lazy_fishing", "enabled_all", "disabled");
-            push_value_or_default(&mut notes, game_hash, "logic_level", "normal_logic");
+            warnings.push(WarningKind::GameAdvisory, game_name, "contains a Sonic Adventure DX");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_level", "normal_logic");
         }
-        Some("Tyrian") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "standard"),
+        Some("Tyrian") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "standard"),
         Some("ANIMAL WELL") => {
-            push_value_or_default(&mut notes, game_hash, "tanking_damage", "false");
-            push_value_or_default(&mut notes, game_hash, "bubble_jumping", "short_chains");
-            push_value_or_default(&mut notes, game_hash, "disc_hopping", "off");
-            push_value_or_default(&mut notes, game_hash, "wheel_tricks", "off");
-            push_value_or_default(&mut notes, game_hash, "ball_throwing", "simple");
-            push_value_or_default(&mut notes, game_hash, "flute_jumps", "false");
-            push_value_or_default(&mut notes, game_hash, "obscure_tricks", "false");
-            push_value_or_default(&mut notes, game_hash, "precise_tricks", "false");
-        }
-        Some("DORONKO WANKO") => push_value_or_default(&mut notes, game_hash, "logic", "standard"),
+            push_value_or_default(&mut notes, game_hash, triggers, "tanking_damage", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "bubble_jumping", "short_chains");
+            push_value_or_default(&mut notes, game_hash, triggers, "disc_hopping", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "wheel_tricks", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "ball_throwing", "simple");
+            push_value_or_default(&mut notes, game_hash, triggers, "flute_jumps", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "obscure_tricks", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "precise_tricks", "false");
+        }
+        Some("DORONKO WANKO") => push_value_or_default(&mut notes, game_hash, triggers, "logic", "standard"),
         Some("Minit") => {
-            push_value_or_default(&mut notes, game_hash, "darkrooms", "minor");
-            push_value_or_default(&mut notes, game_hash, "obscure", "false");
-            push_value_or_default(&mut notes, game_hash, "damage_boosts", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "darkrooms", "minor");
+            push_value_or_default(&mut notes, game_hash, triggers, "obscure", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "damage_boosts", "false");
         }
-        Some("Majora's Mask Recompiled") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "normal"),
-        Some("Brotato") => push_value_or_default(&mut notes, game_hash, "enable_abyssal_terrors_dlc", "false"),
+        Some("Majora's Mask Recompiled") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "normal"),
+        Some("Brotato") => push_value_or_default(&mut notes, game_hash, triggers, "enable_abyssal_terrors_dlc", "false"),
         Some("Against the Storm") => {
-            if let Some(enable_dlc) = game_hash.get(&Yaml::from_str("enable_dlc")).cloned() {
-                game_hash.insert(Yaml::from_str("enable_keepers_dlc"), enable_dlc.clone());
-                game_hash.insert(Yaml::from_str("enable_nightwatchers_dlc"), enable_dlc.clone());
-                game_hash.insert(Yaml::from_str("enable_biome_keys"), enable_dlc);
-            }
-
-            push_value_or_default(&mut notes, game_hash, "enable_keepers_dlc", "false");
-            push_value_or_default(&mut notes, game_hash, "enable_nightwatchers_dlc", "false");
-            push_value_or_default(&mut notes, game_hash, "enable_biome_keys", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "enable_keepers_dlc", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "enable_nightwatchers_dlc", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "enable_biome_keys", "false");
         }
         // IGNORE
         Some("Guild Wars 2") => {
             game_hash.insert(Yaml::from_str("achievement_weight"), Yaml::Integer(0));
-            push_value_or_default(&mut notes, game_hash, "storyline", "core");
-        }
-        Some("Paper Mario") => rename_true_false(game_hash, "super_multi_blocks", "anywhere", "off"),
-        Some("Gauntlet Legends") => {
-            if let Some(traps_frequency) = game_hash.get_mut(&Yaml::from_str("traps_frequency")) {
-                move_option_weight(traps_frequency, "normal", "10");
-                move_option_weight(traps_frequency, "large", "15");
-                move_option_weight(traps_frequency, "extreme", "50");
-            }
-
-            if let Some(obelisks) = game_hash.get_mut(&Yaml::from_str("obelisks")) {
-                move_option_weight(obelisks, "none", "false");
-                move_option_weight(obelisks, "all_obelisks", "true");
-            }
-
-            if let Some(mirror_shards) = game_hash.get_mut(&Yaml::from_str("mirror_shards")) {
-                move_option_weight(mirror_shards, "none", "false");
-                move_option_weight(mirror_shards, "all_shards", "true");
-            }
-
-            if let Some(max_difficulty_value) = game_hash.remove(&Yaml::from_str("max_difficulty_value")) {
-                game_hash.insert(Yaml::from_str("max_difficulty"), max_difficulty_value);
-            }
-            game_hash.remove(&Yaml::from_str("max_difficulty_toggle"));
+            push_value_or_default(&mut notes, game_hash, triggers, "storyline", "core");
         }
         Some("Pokemon Crystal") => {
-            rename_true_false(game_hash, "enable_mischief", "mild", "off");
-
-            if let Some(learnset_type_bias) = game_hash.get_mut(&Yaml::from_str("learnset_type_bias")) {
-                move_option_weight(learnset_type_bias, "vanilla", "none");
-            }
-
             let mut trap_weights: LinkedHashMap<_, _> = [
                 ("phone_trap_weight", "Phone Trap"),
                 ("sleep_trap_weight", "Sleep Trap"),
@@ -745,7 +562,7 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             ]
             .into_iter()
             .map(|(option_name, trap_name)| {
-                resolve_weighted_option(game_hash, option_name);
+                resolve_weighted_option(game_hash, option_name, rng);
                 game_hash
                     .remove(&Yaml::from_str(option_name))
                     .map(|yaml| (Yaml::from_str(trap_name), yaml))
@@ -762,171 +579,56 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             if let Some(trainer_name) = game_hash.get(&Yaml::from_str("trainer_name")).and_then(|name| name.as_str())
                 && !trainer_name.is_empty()
             {
-                println!("'{name}.yaml' has trainer_name '{trainer_name}'");
-            }
-
-            rename_true_false(game_hash, "require_itemfinder", "hard_required", "not_required");
-            rename_true_false(game_hash, "randomize_wilds", "completely_random", "vanilla");
-            rename_true_false(game_hash, "randomize_music", "completely_random", "off");
-        }
-        Some("The Witness") => {
-            if let Some(elevators_come_to_you) = game_hash.get_mut(&Yaml::from_str("elevators_come_to_you")) {
-                move_option_weight_to_yaml(
-                    elevators_come_to_you,
-                    "true",
-                    Yaml::Array(vec![Yaml::from_str("Quarry Elevator"), Yaml::from_str("Swamp Long Bridge"), Yaml::from_str("Bunker Elevator")]),
-                );
-                move_option_weight_to_yaml(elevators_come_to_you, "false", Yaml::Hash(LinkedHashMap::new()));
+                warnings.push(WarningKind::GameAdvisory, game_name, format!("has trainer_name '{trainer_name}'"));
             }
         }
-        Some("Final Fantasy Tactics Advance") => rename_true_false(game_hash, "progressive_shop_battle_unlock", "enabled", "disabled"),
         Some("Rain World") => {
-            game_hash.remove(&Yaml::from_str("which_game_version"));
+            push_value_or_default(&mut notes, game_hash, triggers, "which_game_version", "1_10_4");
+            push_value_or_default(&mut notes, game_hash, triggers, "is_msc_enabled", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "is_watcher_enabled", "false");
+        }
+        Some("Sentinels of the Multiverse") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "enabled_sets", "[Official]");
+        }
+        Some("Hatsune Miku Project Diva Mega Mix+") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "allow_megamix_dlc_songs", "false");
+            let mod_str = if let Some(yaml) = game_hash.get_mut(&Yaml::from_str("megamix_mod_data")) {
+                if let Some(Value::Object(mut map)) = yaml.as_str().and_then(|str| serde_json::from_str(str).ok()) {
+                    let mut changed = false;
+                    changed |= map.remove("EdenDarkPack").is_some();
+                    let mod_str = map.keys().cloned().collect::<Vec<_>>().join(", ");
+                    if changed {
+                        if let Ok(new) = serde_json::to_string(&Value::Object(map)) {
+                            *yaml = Yaml::from_str(&new);
+                        } else {
+                            game_hash.remove(&Yaml::from_str("megamix_mod_data"));
 
-            if let Some(which_victory_condition) = game_hash.get_mut(&Yaml::from_str("which_victory_condition")) {
-                move_option_weight(which_victory_condition, "alternate", "echoes");
-                move_option_weight(which_victory_condition, "weaver", "ascension");
+                            warnings.push(WarningKind::GameAdvisory, game_name, "failed to serialize new mod list, all mods have been removed");
+                        }
+                    }
+                    mod_str
+                } else {
+                    String::new()
+                }
+            } else {
+                String::new()
+            };
 
-                if which_victory_condition.as_str().is_some_and(|str| str == "ascension")
-                    || which_victory_condition
-                        .as_hash()
-                        .is_some_and(|hash| hash.get(&Yaml::from_str("ascension")).is_some_and(|v| v.as_i64().is_some_and(|weight| weight > 0)))
+            notes.push(Note::new("megamix_mod_data", format!("[{mod_str}]")));
+        }
+        Some("Luigi's Mansion") => {
+            let vacuum_upgrades = Yaml::from_str("vacuum_upgrades");
+            if game_hash.get(&vacuum_upgrades).is_none() {
+                resolve_weighted_option(game_hash, "good_vacuum", rng);
+                if let Some(yaml) = game_hash.get(&Yaml::from_str("good_vacuum"))
+                    && let Some(upgrades) = match yaml.as_str() {
+                        Some("start_with") => Some(5),
+                        Some("include") => Some(3),
+                        Some("exclude") => Some(0),
+                        _ => None,
+                    }
                 {
-                    game_hash.insert(Yaml::from_str("randomize_weaver"), Yaml::Boolean(true));
-                }
-            }
-
-            if let Some(checks_spread_rot) = game_hash.get_mut(&Yaml::from_str("checks_spread_rot")) {
-                move_option_weight(checks_spread_rot, "off", "false");
-                move_option_weight(checks_spread_rot, "on", "true");
-                move_option_weight(checks_spread_rot, "prince_ending_only", "related_ending_only");
-            }
-
-            push_value_or_default(&mut notes, game_hash, "which_game_version", "1_10_4");
-            push_value_or_default(&mut notes, game_hash, "is_msc_enabled", "false");
-            push_value_or_default(&mut notes, game_hash, "is_watcher_enabled", "false");
-        }
-        Some("Sentinels of the Multiverse") => {
-            if let Some(filler_weights) = game_hash.get_mut(&Yaml::from_str("filler_weights")).and_then(|yaml| yaml.as_mut_vec()) {
-                for entry in filler_weights {
-                    if let Some(hash) = entry.as_mut_hash() {
-                        hash.remove(&Yaml::from_str("typed"));
-                    }
-                }
-            }
-            if let Some(pool_size) = game_hash.get_mut(&Yaml::from_str("pool_size")).and_then(|yaml| yaml.as_mut_hash()) {
-                for (_, v) in pool_size {
-                    if let Some(str) = v.as_str()
-                        && let Some((val, _)) = str.split_once('+')
-                    {
-                        *v = Yaml::from_str(val);
-                    }
-                }
-            }
-            if let Some(location_density) = game_hash.get_mut(&Yaml::from_str("location_density")).and_then(|yaml| yaml.as_mut_hash()) {
-                let key = Yaml::from_str("hero");
-                if !location_density.contains_key(&key) {
-                    location_density.insert(key, Yaml::Integer(0));
-                }
-                if let Some(value) = location_density.remove(&Yaml::from_str("variant")) {
-                    location_density.insert(Yaml::from_str("variant_unlock"), value);
-                } else {
-                    location_density.insert(Yaml::from_str("variant_unlock"), Yaml::Integer(0));
-                }
-            }
-            push_value_or_default(&mut notes, game_hash, "enabled_sets", "[Official]");
-        }
-        Some("Hatsune Miku Project Diva Mega Mix+") => {
-            game_hash.remove(&Yaml::from_str("exclude_singers"));
-
-            push_value_or_default(&mut notes, game_hash, "allow_megamix_dlc_songs", "false");
-            let mod_str = if let Some(yaml) = game_hash.get_mut(&Yaml::from_str("megamix_mod_data")) {
-                if let Some(Value::Object(mut map)) = yaml.as_str().and_then(|str| serde_json::from_str(str).ok()) {
-                    let mut changed = false;
-                    changed |= map.remove("EdenDarkPack").is_some();
-                    let mod_str = map.keys().cloned().collect::<Vec<_>>().join(", ");
-                    if changed {
-                        if let Ok(new) = serde_json::to_string(&Value::Object(map)) {
-                            *yaml = Yaml::from_str(&new);
-                        } else {
-                            game_hash.remove(&Yaml::from_str("megamix_mod_data"));
-
-                            println!("Failed to serialize new mod list for '{name}.yaml', all mods have been removed");
-                        }
-                    }
-                    mod_str
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            };
-
-            notes.push(format!("megamix_mod_data: [{mod_str}]",));
-        }
-        Some("Refunct") => {
-            if let Some(hash) = game_hash.get_mut(&Yaml::from_str("minigames_likeliness")).and_then(|yaml| yaml.as_mut_hash()) {
-                for minigame in &["Climb Narrow Minigame", "Clique", "Funny Bridge Game Minigame", "Rando Mountain Minigame"] {
-                    let key = Yaml::from_str(minigame);
-                    if !hash.contains_key(&key) {
-                        hash.insert(key, Yaml::Integer(0));
-                    }
-                }
-            }
-        }
-        Some("Jigsaw") => {
-            game_hash.remove(&Yaml::from_str("permillage_of_checks_out_of_logic"));
-            game_hash.remove(&Yaml::from_str("maximum_number_of_real_items"));
-            game_hash.remove(&Yaml::from_str("minimum_number_of_pieces_per_real_item"));
-            game_hash.remove(&Yaml::from_str("enable_forced_local_filler_items"));
-
-            let grid_type_yaml = game_hash.remove(&Yaml::from_str("grid_type"));
-            let rotatoins_yaml = game_hash.remove(&Yaml::from_str("rotations"));
-
-            let grid_type = grid_type_yaml.as_ref().and_then(Yaml::as_str).unwrap_or("square");
-            let rotations = rotatoins_yaml.as_ref().and_then(Yaml::as_str).unwrap_or("no_rotation");
-
-            game_hash.insert(
-                Yaml::from_str("grid_type_and_rotations"),
-                Yaml::from_str(match (grid_type, rotations) {
-                    ("square", "no_rotation") => "square_no_rotation",
-                    ("square", "per_90_degrees") => "square_90_rotation",
-                    ("square", "per_180_degrees") => "square_180_rotation",
-                    ("hexagonal", "no_rotation") => "hex_no_rotation",
-                    ("hexagonal", "per_90_degrees") => "hex_60_rotation",
-                    ("hexagonal", "per_180_degrees") => "hex_180_rotation",
-                    ("meme_one_row", "no_rotation") => "meme_one_row_no_rotation",
-                    ("meme_one_row", "per_180_degrees") => "meme_one_row_180_rotation",
-                    ("meme_one_column", "no_rotation") => "meme_one_column_no_rotation",
-                    ("meme_one_column", "per_180_degrees") => "meme_one_column_180_rotation",
-                    (_, _) => "square_no_rotations",
-                }),
-            );
-        }
-        Some("Psychonauts") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("Goal")) {
-                move_option_weight(goal, "braintank_and_brainhunt", "asylum_brain_tank_and_brain_hunt");
-            }
-
-            if game_hash.remove(&Yaml::from_str("LootboxVaults")).is_some() {
-                game_hash.insert(Yaml::from_str("VaultCount"), Yaml::Integer(101));
-            }
-        }
-        Some("Luigi's Mansion") => {
-            rename_true_false(game_hash, "door_rando", "randomized", "off");
-
-            let vacuum_upgrades = Yaml::from_str("vacuum_upgrades");
-            if game_hash.get(&vacuum_upgrades).is_none() {
-                resolve_weighted_option(game_hash, "good_vacuum");
-                if let Some(yaml) = game_hash.get(&Yaml::from_str("good_vacuum"))
-                    && let Some(upgrades) = match yaml.as_str() {
-                        Some("start_with") => Some(5),
-                        Some("include") => Some(3),
-                        Some("exclude") => Some(0),
-                        _ => None,
-                    }
-                {
-                    game_hash.insert(vacuum_upgrades, Yaml::Integer(upgrades));
+                    game_hash.insert(vacuum_upgrades, Yaml::Integer(upgrades));
                 }
             }
 
@@ -943,7 +645,7 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                     ("Gems", "gems_weight"),
                     ("Hearts", "heart_weight"),
                 ] {
-                    resolve_weighted_option(game_hash, old);
+                    resolve_weighted_option(game_hash, old, rng);
                     if let Some(val) = game_hash.remove(&Yaml::from_str(old)) {
                         hash.insert(Yaml::from_str(new), val);
                     }
@@ -969,7 +671,7 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                     ("Spooky Time", "spooky_weight"),
                     ("Squash Trap", "squash_weight"),
                 ] {
-                    resolve_weighted_option(game_hash, old);
+                    resolve_weighted_option(game_hash, old, rng);
                     if let Some(val) = game_hash.remove(&Yaml::from_str(old)) {
                         hash.insert(Yaml::from_str(new), val);
                     }
@@ -978,130 +680,54 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 game_hash.insert(trap_weights, Yaml::Hash(hash));
             }
         }
-        Some("Factorio") => {
-            if let Some(world_gen) = game_hash.get_mut(&Yaml::from_str("world_gen")).and_then(|yaml| yaml.as_mut_hash()) {
-                world_gen.remove(&Yaml::from_str("terrain_segmentation"));
-            }
-        }
-        Some("Ty the Tasmanian Tiger") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "standard"),
-        Some("Paper Mario The Thousand Year Door") => {
-            if let Some(chapter_clears) = game_hash.remove(&Yaml::from_str("chapter_clears")) {
-                game_hash.insert(Yaml::from_str("goal_stars"), chapter_clears.clone());
-                game_hash.insert(Yaml::from_str("palace_stars"), chapter_clears);
-                game_hash.insert(Yaml::from_str("goal"), Yaml::from_str("crystal_stars"));
-            }
-
-            if let Some(starting_partner) = game_hash.get_mut(&Yaml::from_str("starting_partner")) {
-                move_option_weight(starting_partner, "from_partner", "random");
-            }
-
-            if let Some(yoshi_color) = game_hash.get_mut(&Yaml::from_str("yoshi_color")) {
-                move_option_weight(yoshi_color, "random_color", "random");
-            }
-
-            rename_true_false(game_hash, "star_shuffle", "all", "vanilla");
-
-            if option_can_be_other_than(game_hash, "yoshi_name", &Yaml::from_str("Yoshi"), &Yaml::from_str("Yoshi")) {
-                println!("'{name}.yaml' has a modified yoshi name");
-            }
-        }
-        Some("Golden Sun The Lost Age") => {
-            if let Some(enemy_elemental_resistance) = game_hash.get_mut(&Yaml::from_str("enemy_elemental_resistance")) {
-                move_option_weight(enemy_elemental_resistance, "shuffle_elemmental_res", "shuffle_elemental_res");
-            }
-        }
-        Some("The Minish Cap") => {
-            if option_can_be(game_hash, "goal_vaati", &Yaml::Boolean(true), &Yaml::Boolean(false)) {
-                game_hash.insert(Yaml::from_str("goal"), Yaml::from_str("pedestal"));
-            } else {
-                game_hash.remove(&Yaml::from_str("goal_vaati"));
-            }
-        }
-        Some("Minishoot Adventures") => {
-            if let Some(completion_goals) = game_hash.get_mut(&Yaml::from_str("completion_goals")) {
-                move_option_weight(completion_goals, "both", "dungeon_5_and_snow");
-            }
-        }
-        Some("Stacklands") => {
-            if option_can_be(game_hash, "goal", &Yaml::from_str(""), &Yaml::from_str("kill_wicked_witch")) {
-                game_hash.insert(Yaml::from_str("boards"), Yaml::from_str("mainland_and_forest"));
-            } else if option_can_be(game_hash, "goal", &Yaml::from_str(""), &Yaml::from_str("kill_demon")) {
-                game_hash.insert(Yaml::from_str("boards"), Yaml::from_str("mainland_only"));
-            }
-
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "kill_demon", "random_boss");
-                move_option_weight(goal, "kill_wicked_witch", "random_boss");
-            }
+        Some("Ty the Tasmanian Tiger") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "standard"),
+        Some("Paper Mario The Thousand Year Door") if option_can_be_other_than(game_hash, "yoshi_name", &Yaml::from_str("Yoshi"), &Yaml::from_str("Yoshi")) => {
+            warnings.push(WarningKind::GameAdvisory, game_name, "has a modified yoshi name");
         }
         // IGNORE
         Some("Oxygen Not Included") => {
-            push_value_or_default(&mut notes, game_hash, "spaced_out", "true");
-            push_value_or_default(&mut notes, game_hash, "frosty", "true");
-            push_value_or_default(&mut notes, game_hash, "bionic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "spaced_out", "true");
+            push_value_or_default(&mut notes, game_hash, triggers, "frosty", "true");
+            push_value_or_default(&mut notes, game_hash, triggers, "bionic", "false");
         }
         Some("Monster Sanctuary") => {
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "casual");
-            push_value_or_default(&mut notes, game_hash, "tedious_checks", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "casual");
+            push_value_or_default(&mut notes, game_hash, triggers, "tedious_checks", "false");
         }
         Some("Spelunky 2") => {
-            push_value_or_default(&mut notes, game_hash, "include_hard_locations", "false");
-            push_value_or_default(&mut notes, game_hash, "can_ankh_skip", "false");
-            push_value_or_default(&mut notes, game_hash, "can_udjat_skip", "false");
-            push_value_or_default(&mut notes, game_hash, "can_qilin_skip", "false");
-            push_value_or_default(&mut notes, game_hash, "can_kingu_skip", "false");
-            push_value_or_default(&mut notes, game_hash, "can_mothership_skip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "include_hard_locations", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "can_ankh_skip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "can_udjat_skip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "can_qilin_skip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "can_kingu_skip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "can_mothership_skip", "false");
         }
         Some("Metroid: Zero Mission") => {
-            if let Some(walljumps_in_logic) = game_hash.remove(&Yaml::from_str("walljumps_in_logic")) {
-                game_hash.insert(Yaml::from_str("walljumps"), walljumps_in_logic);
-            }
-
-            rename_true_false(game_hash, "walljumps", "enabled", "disabled");
-            rename_true_false(game_hash, "hazard_runs", "normal", "disabled");
-            game_hash.remove(&Yaml::from_str("unknown_items_always_usable"));
-
-            push_value_or_default(&mut notes, game_hash, "walljumps", "enabled");
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "simple");
-            push_value_or_default(&mut notes, game_hash, "combat_logic_difficulty", "relaxed");
-            push_value_or_default(&mut notes, game_hash, "ibj_in_logic", "none");
-            push_value_or_default(&mut notes, game_hash, "hazard_runs", "disabled");
-            push_value_or_default(&mut notes, game_hash, "tricky_shinesparks", "false");
-            push_value_or_default(&mut notes, game_hash, "tricks_allowed", "[]");
+            push_value_or_default(&mut notes, game_hash, triggers, "walljumps", "enabled");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "simple");
+            push_value_or_default(&mut notes, game_hash, triggers, "combat_logic_difficulty", "relaxed");
+            push_value_or_default(&mut notes, game_hash, triggers, "ibj_in_logic", "none");
+            push_value_or_default(&mut notes, game_hash, triggers, "hazard_runs", "disabled");
+            push_value_or_default(&mut notes, game_hash, triggers, "tricky_shinesparks", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "tricks_allowed", "[]");
         }
         Some("Cuphead") => {
-            rename_true_false(game_hash, "dlc_boss_chalice_checks", "enabled", "disabled");
-            rename_true_false(game_hash, "dlc_rungun_chalice_checks", "enabled", "disabled");
-            rename_true_false(game_hash, "dlc_kingdice_chalice_checks", "enabled", "disabled");
-            rename_true_false(game_hash, "dlc_chess_chalice_checks", "enabled", "disabled");
-            rename_true_false(game_hash, "level_shuffle", "enabled", "disabled");
-            push_value_or_default(&mut notes, game_hash, "dlc_boss_chalice_checks", "disabled");
-            push_value_or_default(&mut notes, game_hash, "dlc_rungun_chalice_checks", "disabled");
-            push_value_or_default(&mut notes, game_hash, "dlc_kingdice_chalice_checks", "disabled");
-            push_value_or_default(&mut notes, game_hash, "dlc_chess_chalice_checks", "disabled");
-            push_value_or_default(&mut notes, game_hash, "dlc_cactusgirl_quest", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_boss_chalice_checks", "disabled");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_rungun_chalice_checks", "disabled");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_kingdice_chalice_checks", "disabled");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_chess_chalice_checks", "disabled");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_cactusgirl_quest", "false");
         }
         Some("Metroid Fusion") => {
-            if let Some(tricky_shinesparks_in_region_logic) = game_hash.remove(&Yaml::from_str("TrickyShinesparksInRegionLogic")) {
-                game_hash.insert(Yaml::from_str("ShinesparkTrickDifficulty"), tricky_shinesparks_in_region_logic);
-            }
-
-            push_value_or_default(&mut notes, game_hash, "PointOfNoReturnsInLogic", "true");
-            push_value_or_default(&mut notes, game_hash, "ShinesparkTrickDifficulty", "none");
-            push_value_or_default(&mut notes, game_hash, "WallJumpTrickDifficulty", "none");
-            push_value_or_default(&mut notes, game_hash, "CombatDifficulty", "beginner");
+            push_value_or_default(&mut notes, game_hash, triggers, "PointOfNoReturnsInLogic", "true");
+            push_value_or_default(&mut notes, game_hash, triggers, "ShinesparkTrickDifficulty", "none");
+            push_value_or_default(&mut notes, game_hash, triggers, "WallJumpTrickDifficulty", "none");
+            push_value_or_default(&mut notes, game_hash, triggers, "CombatDifficulty", "beginner");
         }
         Some("Simpsons Hit and Run") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "goal: all missions complete!", "goal_all_missions_complete");
-                move_option_weight(goal, "goal: all story missions complete!", "goal_all_story_missions_complete");
-                move_option_weight(goal, "goal: final mission(l7m7)", "goal_final_missionl7m7");
-                move_option_weight(goal, "goal: wasps and cards collected!", "goal_wasps_and_cards_collected");
-            }
-
-            resolve_weighted_option(game_hash, "shufflegagfinder");
-            resolve_weighted_option(game_hash, "shufflecheckeredflags");
-            resolve_weighted_option(game_hash, "shuffleebrake");
+            resolve_weighted_option(game_hash, "shufflegagfinder", rng);
+            resolve_weighted_option(game_hash, "shufflecheckeredflags", rng);
+            resolve_weighted_option(game_hash, "shuffleebrake", rng);
 
             if let Some(shufflegagfinder) = game_hash.get_mut(&Yaml::from_str("shufflegagfinder")) {
                 move_option_weight_to_yaml(shufflegagfinder, "false", Yaml::Array(Vec::new()));
@@ -1118,157 +744,44 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 move_option_weight_to_yaml(shuffleebrake, "true", Yaml::Array(vec![Yaml::from_str("All")]));
             }
         }
-        Some("Satisfactory") => {
-            if let Some(mut final_elevator_package) = game_hash.remove(&Yaml::from_str("final_elevator_package")) {
-                move_option_weight(&mut final_elevator_package, "one package (tiers 1-2)", "phase 1 (tiers 1-2)");
-                move_option_weight(&mut final_elevator_package, "two packages (tiers 1-4)", "phase 2 (tiers 1-4");
-                move_option_weight(&mut final_elevator_package, "three packages (tiers 1-6)", "phase 3 (tiers 1-6)");
-                move_option_weight(&mut final_elevator_package, "four packages (tiers 1-8)", "phase 3 (tiers 1-6)");
-                move_option_weight(&mut final_elevator_package, "five packages (tiers 1-9)", "phase 3 (tiers 1-6)");
-                move_option_weight(&mut final_elevator_package, "phase 4 (tiers 1-8)", "phase 3 (tiers 1-6)");
-                move_option_weight(&mut final_elevator_package, "phase 5 (tiers 1-9)", "phase 3 (tiers 1-6)");
-                move_option_weight(&mut final_elevator_package, "4", "3");
-                move_option_weight(&mut final_elevator_package, "5", "3");
-                move_option_weight(&mut final_elevator_package, "random", "random-range-1-3");
-                move_option_weight(&mut final_elevator_package, "random-low", "random-low-range-1-3");
-                move_option_weight(&mut final_elevator_package, "random-high", "random-high-range-1-3");
-                game_hash.insert(Yaml::from_str("final_elevator_phase"), final_elevator_package);
-            }
+        Some("The Legend of Zelda - Phantom Hourglass") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic", "normal");
         }
-        Some("Trackmania") => {
-            if let Some(disable_bronze) = game_hash.remove(&Yaml::from_str("disable_bronze")) {
-                game_hash.insert(Yaml::from_str("disable_bronze_locations"), disable_bronze.clone());
-                game_hash.insert(Yaml::from_str("disable_bronze_medals"), disable_bronze);
-            }
+        Some("Ape Escape 3") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_preference", "normal");
+            push_value_or_default(&mut notes, game_hash, triggers, "hip_drop_storage_logic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "prolonged_quad_jump_logic", "false");
+        }
+        Some("Ape Escape") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic", "normal");
+            push_value_or_default(&mut notes, game_hash, triggers, "infinitejump", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "superflyer", "false");
+        }
+        Some("Donkey Kong 64") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_type", "glitchless");
+            push_value_or_default(&mut notes, game_hash, triggers, "glitches_selected", "[]");
+        }
+        Some("Pokemon Black and White") => {
+            resolve_weighted_option(game_hash, "version", rng);
 
-            if let Some(disable_silver) = game_hash.remove(&Yaml::from_str("disable_silver")) {
-                game_hash.insert(Yaml::from_str("disable_silver_locations"), disable_silver.clone());
-                game_hash.insert(Yaml::from_str("disable_silver_medals"), disable_silver);
+            if option_can_be(game_hash, "version", &Yaml::from_str("random"), &Yaml::from_str("random")) {
+                let mut new_hash = LinkedHashMap::new();
+                new_hash.insert(Yaml::from_str("black"), Yaml::Integer(50));
+                new_hash.insert(Yaml::from_str("white"), Yaml::Integer(50));
+                game_hash.insert(Yaml::from_str("version"), Yaml::Hash(new_hash));
             }
 
-            if let Some(disable_gold) = game_hash.remove(&Yaml::from_str("disable_gold")) {
-                game_hash.insert(Yaml::from_str("disable_gold_locations"), disable_gold.clone());
-                game_hash.insert(Yaml::from_str("disable_gold_medals"), disable_gold);
-            }
+            resolve_weighted_option(game_hash, "version", rng);
 
-            if let Some(disable_author) = game_hash.remove(&Yaml::from_str("disable_author")) {
-                game_hash.insert(Yaml::from_str("disable_author_locations"), disable_author);
-            }
+            push_value_or_default(&mut notes, game_hash, triggers, "version", "N/A");
         }
-        Some("The Legend of Zelda - Phantom Hourglass") => {
-            rename_true_false(game_hash, "randomize_harrow", "randomize_without_hints", "no_harrow");
-
-            if let Some(goal_requirements) = game_hash.get_mut(&Yaml::from_str("goal_requirements")) {
-                move_option_weight(goal_requirements, "complete_dungeons", "defeat_bosses");
-            }
-
-            game_hash.remove(&Yaml::from_str("dungeon_hints"));
+        // IGNORE
+        Some("Archipela-Go") => {
+            let max_key = Yaml::from_str("maximum_distance");
+            let min_key = Yaml::from_str("minimum_distance");
 
-            rename_true_false(game_hash, "shuffle_dungeon_entrances", "shuffle", "no_shuffle");
-
-            if let Some(additional_metal_names) = game_hash.get_mut(&Yaml::from_str("additional_metal_names")) {
-                move_option_weight(additional_metal_names, "custom_unique", "custom_prefer_vanilla");
-            }
-
-            if option_can_be(game_hash, "shuffle_island_entrances", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
-                game_hash.insert(Yaml::from_str("shuffle_ports"), Yaml::from_str("shuffle"));
-                game_hash.insert(Yaml::from_str("shuffle_caves"), Yaml::from_str("shuffle"));
-                game_hash.insert(Yaml::from_str("shuffle_houses"), Yaml::from_str("shuffle"));
-                game_hash.remove(&Yaml::from_str("shuffle_island_entrances"));
-            }
-
-            push_value_or_default(&mut notes, game_hash, "logic", "normal");
-        }
-        // IGNORE
-        Some("Bloons TD6") => {
-            if let Some(trap_weights) = game_hash.get_mut(&Yaml::from_str("trap_weights")).and_then(|trap_weights| trap_weights.as_mut_hash()) {
-                for trap in [
-                    "144p Trap",
-                    "Chaos Control Trap",
-                    "Flood Trap",
-                    "Input Sequence Trap",
-                    "Math Quiz Trap",
-                    "Number Sequence Trap",
-                    "Pokemon Trivia Trap",
-                    "Screen Flip Trap",
-                    "Shuffle Trap",
-                    "Swap Trap",
-                    "Trivia Trap",
-                    "Yap Trap",
-                    "Zoom Trap",
-                ] {
-                    let key = Yaml::from_str(trap);
-                    if !trap_weights.contains_key(&key) {
-                        trap_weights.insert(key, Yaml::Integer(0));
-                    }
-                }
-            }
-        }
-        Some("Astalon") => rename_true_false(game_hash, "fast_blood_chalice", "always", "off"),
-        Some("Anodyne") => {
-            if let Some(red_cave_access) = game_hash.remove(&Yaml::from_str("red_cave_access")) {
-                game_hash.insert(Yaml::from_str("red_grotto_access"), red_cave_access);
-            }
-        }
-        Some("Ape Escape 3") => {
-            if let Some(goal_target_override) = game_hash.get_mut(&Yaml::from_str("goal_target_override")) {
-                move_option_weight(goal_target_override, "disable", "1");
-            }
-
-            push_value_or_default(&mut notes, game_hash, "logic_preference", "normal");
-            push_value_or_default(&mut notes, game_hash, "hip_drop_storage_logic", "false");
-            push_value_or_default(&mut notes, game_hash, "prolonged_quad_jump_logic", "false");
-        }
-        Some("Ape Escape") => {
-            if let Some(entrance) = game_hash.get_mut(&Yaml::from_str("entrance")) {
-                move_option_weight(entrance, "on", "recommended");
-                move_option_weight(entrance, "lockmm", "recommended");
-            }
-
-            push_value_or_default(&mut notes, game_hash, "logic", "normal");
-            push_value_or_default(&mut notes, game_hash, "infinitejump", "false");
-            push_value_or_default(&mut notes, game_hash, "superflyer", "false");
-        }
-        Some("Super Mario Land 2") => {
-            if let Some(mario_coin_fragment_percentage) = game_hash.get_mut(&Yaml::from_str("mario_coin_fragment_percentage")) {
-                move_option_weight_matches(mario_coin_fragment_percentage, |yaml| as_i64(yaml).is_some_and(|v| v >= 50), "50");
-            }
-        }
-        Some("Donkey Kong 64") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "krool", "acquire_key_8");
-                move_option_weight(goal, "all_keys", "acquire_key_8");
-            }
-
-            push_value_or_default(&mut notes, game_hash, "logic_type", "glitchless");
-            push_value_or_default(&mut notes, game_hash, "glitches_selected", "[]");
-        }
-        Some("Pokemon Black and White") => {
-            resolve_weighted_option(game_hash, "version");
-
-            if option_can_be(game_hash, "version", &Yaml::from_str("random"), &Yaml::from_str("random")) {
-                let mut new_hash = LinkedHashMap::new();
-                new_hash.insert(Yaml::from_str("black"), Yaml::Integer(50));
-                new_hash.insert(Yaml::from_str("white"), Yaml::Integer(50));
-                game_hash.insert(Yaml::from_str("version"), Yaml::Hash(new_hash));
-            }
-
-            resolve_weighted_option(game_hash, "version");
-
-            push_value_or_default(&mut notes, game_hash, "version", "N/A");
-        }
-        Some("Mario Kart 64") => {
-            if let Some(logic_difficulty) = game_hash.get_mut(&Yaml::from_str("logic_difficulty")) {
-                move_option_weight(logic_difficulty, "generous", "basic");
-            }
-        }
-        // IGNORE
-        Some("Archipela-Go") => {
-            let max_key = Yaml::from_str("maximum_distance");
-            let min_key = Yaml::from_str("minimum_distance");
-
-            resolve_weighted_option(game_hash, "maximum_distance");
-            resolve_weighted_option(game_hash, "minimum_distance");
+            resolve_weighted_option(game_hash, "maximum_distance", rng);
+            resolve_weighted_option(game_hash, "minimum_distance", rng);
 
             let mut max_distance = match game_hash.get(&max_key) {
                 Some(Yaml::Integer(value)) => *value,
@@ -1297,63 +810,16 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             game_hash.insert(max_key, Yaml::Integer(max_distance));
             game_hash.insert(min_key, Yaml::Integer(min_distance));
 
-            notes.push(String::from(match max_distance {
+            notes.push(Note::description(match max_distance {
                 ..=5000 => "Walk",
                 5001..=10000 => "Bike",
                 10001.. => "Car Trip",
             }));
         }
         Some("Crystal Project") => {
-            println!("'{name}.yaml' contains a Crystal Project",);
-
-            rename_true_false(game_hash, "regionsanity", "enabled", "disabled");
-            rename_true_false(game_hash, "shopsanity", "enabled", "disabled");
-            rename_true_false(game_hash, "levelGating", "level_set", "none");
-
-            for (old, new) in [
-                ("trapLikelihood", "trap_likelihood"),
-                ("clamshellGoalQuantity", "clamshell_goal_quantity"),
-                ("extraClamshellsInPool", "extra_clamshells_in_pool"),
-                ("newWorldStoneJobQuantity", "new_world_stone_job_quantity"),
-                ("includedRegions", "included_regions"),
-                ("jobRando", "job_rando"),
-                ("startingJobQuantity", "starting_job_quantity"),
-                ("killBossesMode", "kill_bosses_mode"),
-                ("progressiveMountMode", "progressive_mount_mode"),
-                ("levelGating", "level_gating"),
-                ("levelComparedToEnemies", "level_compared_to_enemies"),
-                ("progressiveLevelSize", "progressive_level_size"),
-                ("maxLevel", "max_level"),
-                ("keyMode", "key_mode"),
-                ("obscureRoutes", "obscure_routes"),
-                ("easyLeveling", "easy_leveling"),
-                ("progressiveEquipmentMode", "progressive_equipment_mode"),
-                ("startWithTreasureFinder", "start_with_treasure_finder"),
-                ("startWithMaps", "start_with_maps"),
-                ("includeSummonAbilities", "include_summon_abilities"),
-                ("includeScholarAbilities", "include_scholar_abilities"),
-                ("randomizeMusic", "randomize_music"),
-                ("useMods", "use_mods"),
-            ] {
-                if let Some(value) = game_hash.remove(&Yaml::from_str(old)) {
-                    game_hash.insert(Yaml::from_str(new), value);
-                }
-            }
-        }
-        Some("Rabbit and Steel") => {
-            if let Some(run_type) = game_hash.get_mut(&Yaml::from_str("run_type")) {
-                move_option_weight(run_type, "chaotic", "combined");
-            }
+            warnings.push(WarningKind::GameAdvisory, game_name, "contains a Crystal Project");
         }
-        Some("Yu-Gi-Oh! 2006") => {
-            game_hash.remove(&Yaml::from_str("starter_deck"));
-            game_hash.remove(&Yaml::from_str("normalize_booster_pack_prices"));
-            game_hash.remove(&Yaml::from_str("normalize_booster_pack_rarities"));
-            game_hash.remove(&Yaml::from_str("randomize_pack_contents"));
-            game_hash.remove(&Yaml::from_str("custom_structure_deck"));
-            game_hash.remove(&Yaml::from_str("custom_starter_deck"));
-        }
-        Some("XCOM 2 War of the Chosen") => push_value_or_default(&mut notes, game_hash, "alien_hunters_dlc", "all"),
+        Some("XCOM 2 War of the Chosen") => push_value_or_default(&mut notes, game_hash, triggers, "alien_hunters_dlc", "all"),
         Some("League of Legends") => {
             let champions = if let Some(champions_yaml) = game_hash.get(&Yaml::from_str("champions")) {
                 if let Some(champions) = champions_yaml.as_vec() {
@@ -1369,28 +835,18 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 String::from("all")
             };
 
-            notes.push(format!("champions: {champions}"));
+            notes.push(Note::new("champions", champions.to_string()));
         }
         Some("Rabi-Ribi") => {
-            game_hash.remove(&Yaml::from_str("open_mode"));
-
-            push_value_or_default(&mut notes, game_hash, "knowledge", "basic");
-            push_value_or_default(&mut notes, game_hash, "trick_difficulty", "normal");
-            push_value_or_default(&mut notes, game_hash, "block_clips_required", "false");
-            push_value_or_default(&mut notes, game_hash, "semi_solid_clips_required", "false");
-            push_value_or_default(&mut notes, game_hash, "zips_required", "false");
-            push_value_or_default(&mut notes, game_hash, "bunstrike_zips_required", "false");
-            push_value_or_default(&mut notes, game_hash, "boring_tricks_required", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "knowledge", "basic");
+            push_value_or_default(&mut notes, game_hash, triggers, "trick_difficulty", "normal");
+            push_value_or_default(&mut notes, game_hash, triggers, "block_clips_required", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "semi_solid_clips_required", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "zips_required", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "bunstrike_zips_required", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "boring_tricks_required", "false");
         }
         Some("Spyro 3") => {
-            game_hash.remove(&Yaml::from_str("logic_haunted_agent_9_early"));
-            if let Some(easy_skateboarding) = game_hash.remove(&Yaml::from_str("easy_skateboarding")) {
-                game_hash.insert(Yaml::from_str("easy_skateboarding_lizards"), easy_skateboarding.clone());
-                game_hash.insert(Yaml::from_str("easy_skateboarding_points"), easy_skateboarding.clone());
-                game_hash.insert(Yaml::from_str("easy_skateboarding_lost_fleet"), easy_skateboarding.clone());
-                game_hash.insert(Yaml::from_str("easy_skateboarding_super_bonus_round"), easy_skateboarding);
-            }
-
             let tricks: Vec<_> = [
                 "logic_sunny_sheila_early",
                 "logic_cloud_backwards",
@@ -1427,27 +883,13 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
             .collect();
 
             if tricks.is_empty() {
-                notes.push(String::from("Tricks: none"));
+                notes.push(Note::new("Tricks", "none"));
             } else {
-                notes.push(format!("Tricks: [{}]", tricks.join(", ")));
-            }
-        }
-        Some("PokePark") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "aftergame", "postgame");
-            }
-
-            game_hash.remove(&Yaml::from_str("starting zone"));
-        }
-        Some("Diddy Kong Racing") => {
-            if let Some(mut mirrored_tracks) = game_hash.remove(&Yaml::from_str("mirrored_tracks")) {
-                move_option_weight(&mut mirrored_tracks, "vanilla", "adventure_1");
-                move_option_weight(&mut mirrored_tracks, "mirrored", "adventure_2");
-                game_hash.insert(Yaml::from_str("track_version"), mirrored_tracks);
+                notes.push(Note::new("Tricks", format!("[{}]", tricks.join(", "))));
             }
         }
         Some("The Binding of Isaac Repentance") => {
-            resolve_weighted_option(game_hash, "goal");
+            resolve_weighted_option(game_hash, "goal", rng);
 
             if let Some(goal) = game_hash.remove(&Yaml::from_str("goal"))
                 && let Some(goals) = match goal.as_str() {
@@ -1505,54 +947,7 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 game_hash.insert(Yaml::from_str("goals"), Yaml::Array(goals));
             }
 
-            if let Some(mut split_start_items) = game_hash.remove(&Yaml::from_str("split_start_items")) {
-                move_option_weight(&mut split_start_items, "off", "false");
-                move_option_weight_matches(&mut split_start_items, |yaml| yaml.as_str().is_none_or(|str| str != "off"), "true");
-                game_hash.insert(Yaml::from_str("scatter_previous_items"), split_start_items);
-            }
-
-            if let Some(mut total_locations) = game_hash.remove(&Yaml::from_str("total_locations")) {
-                match &mut total_locations {
-                    Yaml::Integer(value) => {
-                        *value += 1;
-                        *value /= 2
-                    }
-                    Yaml::Hash(option_hash) => {
-                        let mut to_map = vec![];
-
-                        for old in option_hash.keys() {
-                            match old {
-                                Yaml::Integer(value) => to_map.push(*value),
-                                Yaml::String(value) => {
-                                    if let Ok(value) = value.parse() {
-                                        to_map.push(value);
-                                    }
-                                }
-                                _ => (),
-                            }
-                        }
-
-                        for old in to_map {
-                            if let Some(val) = option_hash.remove(&Yaml::Integer(old)) {
-                                option_hash.insert(Yaml::Integer((old + 1) / 2), val);
-                            }
-
-                            if let Some(val) = option_hash.remove(&Yaml::String(old.to_string())) {
-                                option_hash.insert(Yaml::Integer((old + 1) / 2), val);
-                            }
-                        }
-                    }
-                    _ => (),
-                }
-
-                game_hash.insert(Yaml::from_str("additional_item_locations"), total_locations);
-            }
-
-            if let Some(item_pickup_step) = game_hash.remove(&Yaml::from_str("item_pickup_step")) {
-                game_hash.insert(Yaml::from_str("item_location_step"), item_pickup_step);
-            }
-
-            resolve_weighted_option(game_hash, "item_weights");
+            resolve_weighted_option(game_hash, "item_weights", rng);
             if let Some(item_weights) = game_hash.remove(&Yaml::from_str("item_weights")) {
                 if item_weights.as_str().is_some_and(|str| str == "default") {
                     game_hash.remove(&Yaml::from_str("custom_item_weights"));
@@ -1563,330 +958,1113 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 }
             }
 
-            if let Some(custom_junk_item_weights) = game_hash.remove(&Yaml::from_str("custom_junk_item_weights")) {
-                game_hash.insert(Yaml::from_str("junk_weights"), custom_junk_item_weights);
-            }
+            push_value_or_default(&mut notes, game_hash, triggers, "error_room_logic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "trapdoor_logic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "sacrifice_room_logic", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "soul_of_cain_logic", "false");
+        }
+        Some("Nine Sols") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "vanilla"),
+        Some("Lunacid") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "tricks_and_glitches", "[]");
+            push_value_or_default(&mut notes, game_hash, triggers, "challenges", "off");
+        }
+        Some("Powerwash Simulator") => {
+            let mut dlc = vec![];
 
-            if let Some(trap_item_weights) = game_hash.remove(&Yaml::from_str("trap_item_weights")) {
-                game_hash.insert(Yaml::from_str("trap_weights"), trap_item_weights);
+            for option in [
+                "midgar",
+                "tomb_raider",
+                "wallace_and_gromit_dlc",
+                "shrek_dlc",
+                "alice_in_wonderland_dlc",
+                "warhammer_40k_dlc",
+                "back_to_the_future_dlc",
+                "spongebob_dlc",
+            ] {
+                if game_hash.get(&Yaml::from_str(option)).is_some_and(|yaml| yaml.as_vec().is_none_or(|vec| !vec.is_empty())) {
+                    dlc.push(option);
+                }
             }
 
-            if let Some(mut fortunes_are_hints) = game_hash.remove(&Yaml::from_str("fortunes_are_hints")) {
-                move_option_weight(&mut fortunes_are_hints, "false", "0");
-                move_option_weight(&mut fortunes_are_hints, "true", "100");
-
-                game_hash.insert(Yaml::from_str("fortune_machine_hint_percentage"), fortunes_are_hints);
+            if dlc.is_empty() {
+                notes.push(Note::new("dlc", "none"));
+            } else {
+                notes.push(Note::new("dlc", format!("[{}]", dlc.join(", "))));
             }
-
-            game_hash.remove(&Yaml::from_str("required_locations"));
-            game_hash.remove(&Yaml::from_str("force_lategame"));
-            game_hash.remove(&Yaml::from_str("win_collects_missed_locations"));
-            game_hash.remove(&Yaml::from_str("additional_item_locations"));
-            game_hash.remove(&Yaml::from_str("item_location_step"));
-
-            push_value_or_default(&mut notes, game_hash, "error_room_logic", "false");
-            push_value_or_default(&mut notes, game_hash, "trapdoor_logic", "false");
-            push_value_or_default(&mut notes, game_hash, "sacrifice_room_logic", "false");
-            push_value_or_default(&mut notes, game_hash, "soul_of_cain_logic", "false");
         }
-        Some("Nine Sols") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "vanilla"),
-        Some("Lunacid") => {
-            game_hash.remove(&Yaml::from_str("experience"));
-            game_hash.remove(&Yaml::from_str("weapon_experience"));
-
-            push_value_or_default(&mut notes, game_hash, "tricks_and_glitches", "[]");
-            push_value_or_default(&mut notes, game_hash, "challenges", "off");
+        Some("Iji") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "normal_logic");
         }
-        Some("Kirby Super Star") => {
-            if let Some(the_great_cave_offensive_gold_thresholds) = game_hash.get_mut(&Yaml::from_str("the_great_cave_offensive_gold_thresholds")) {
-                match the_great_cave_offensive_gold_thresholds {
-                    Yaml::Real(val) | Yaml::String(val) => {
-                        if let Ok(parsed) = val.parse::<f64>()
-                            && parsed < 1.0
-                        {
-                            *val = (parsed * 100.0).to_string();
+        Some("Rift of the Necrodancer") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_songs", "[]");
+        }
+        Some("Ship of Harkinian") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "enable_all_tricks", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "tricks_in_logic", "[]");
+        }
+        Some("Forged Curse") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "barrier_skip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "damage_boost", "false");
+        }
+        Some("An Untitled Story") => push_value_or_default(&mut notes, game_hash, triggers, "hard_logic", "false"),
+        Some("Portal 2") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "normal");
+        }
+        Some("Star Wars Episode I Racer") => {
+            resolve_weighted_option(game_hash, "progressive_circuits", rng);
+            if let Some(progressive_circuits) = game_hash.remove(&Yaml::from_str("progressive_circuits"))
+                && let Some(course_unlock_mode) = game_hash.get_mut(&Yaml::from_str("course_unlock_mode"))
+            {
+                if course_unlock_mode.as_str().is_some_and(|str| str == "circuit_pass" || str == "circuit_pass_invitational") {
+                    match (progressive_circuits.as_str(), progressive_circuits.as_bool()) {
+                        (Some("true"), _) | (_, Some(true)) => {
+                            *course_unlock_mode = Yaml::from_str("progressive_circuits");
                         }
-                    }
-                    Yaml::Hash(linked_hash_map) => {
-                        let mut new_hash = LinkedHashMap::new();
-
-                        for (key, val) in linked_hash_map.iter() {
-                            if let Yaml::Real(val) | Yaml::String(val) = key
-                                && let Ok(parsed) = val.parse::<f64>()
-                                && parsed < 1.0
-                            {
-                                new_hash.insert(key.to_owned(), Yaml::Real((parsed * 100.0).to_string()));
-                            } else {
-                                new_hash.insert(key.to_owned(), val.to_owned());
-                            }
+                        (Some("false"), _) | (_, Some(false)) => {
+                            *course_unlock_mode = Yaml::from_str("circuits");
                         }
-
-                        *linked_hash_map = new_hash;
+                        _ => (),
                     }
-                    _ => (),
+                } else {
+                    *course_unlock_mode = Yaml::from_str("full_shuffle");
                 }
             }
+        }
+        Some("Donkey Kong Country 2") => push_value_or_default(&mut notes, game_hash, triggers, "logic", "strict"),
+        Some("Baba Is You") => push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "normal"),
+        Some("Spyro 2") => {
+            resolve_weighted_option(game_hash, "open_world_ability_and_warp_unlocks", rng);
+            if option_can_be(game_hash, "open_world_ability_and_warp_unlocks", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
+                game_hash.insert(Yaml::from_str("start_with_abilities"), Yaml::Boolean(true));
+            }
 
-            if let Some(kirby_flavor) = game_hash.remove(&Yaml::from_str("kirby_flavor")) {
-                let mut flavor_hash = LinkedHashMap::new();
-                flavor_hash.insert(Yaml::from_str("default_kirby"), kirby_flavor);
-                game_hash.insert(Yaml::from_str("kirby_flavors"), Yaml::Hash(flavor_hash));
+            if let Some(open_world_ability_and_warp_unlocks) = game_hash.get_mut(&Yaml::from_str("open_world_ability_and_warp_unlocks")) {
+                move_option_weight(open_world_ability_and_warp_unlocks, "vanilla", "generic");
             }
 
-            if let Some(kirby_flavor_preset) = game_hash.get_mut(&Yaml::from_str("kirby_flavor_preset")) {
-                move_option_weight(kirby_flavor_preset, "custom", "default");
+            push_value_or_default(&mut notes, game_hash, triggers, "trick_difficulty", "off");
+            push_value_or_default(&mut notes, game_hash, triggers, "custom_tricks", "[]");
+        }
+        Some("Slime Rancher") => {
+            let skips: Vec<_> = ["easy_skips", "precise_movement", "dangerous_skips", "obscure_locations", "largo_jumps", "jetpack_boosts"]
+                .iter()
+                .filter(|option| option_can_be(game_hash, option, &Yaml::Boolean(false), &Yaml::Boolean(true)))
+                .copied()
+                .collect();
+
+            if skips.is_empty() {
+                notes.push(Note::new("Skips", "none"));
+            } else {
+                notes.push(Note::new("Skips", format!("[{}]", skips.join(", "))));
             }
+
+            push_value_or_default(&mut notes, game_hash, triggers, "enable_stylish_dlc_treasure_pods", "false");
         }
-        Some("Super Mario Sunshine") => {
-            game_hash.remove(&Yaml::from_str("yoshi_mode"));
+        Some("Reventure") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "hardjumps", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic", "{}");
         }
-        Some("Kirby 64 - The Crystal Shards") => {
-            if let Some(total_crystals) = game_hash.remove(&Yaml::from_str("total_crystals")) {
-                game_hash.insert(Yaml::from_str("max_crystals"), total_crystals);
-            }
+        Some("CorruObserver") => push_value_or_default(&mut notes, game_hash, triggers, "mods", "[]"),
+        Some("Little Witch Nobeta") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "wind_requirements", "start_without");
+            push_value_or_default(&mut notes, game_hash, triggers, "skips_in_logic", "[]");
         }
-        Some("Deep Rock Galactic") => {
-            if let Some(max_hazard) = game_hash.get_mut(&Yaml::from_str("max_hazard")) {
-                move_option_weight(max_hazard, "hazard_1", "haz3");
-                move_option_weight(max_hazard, "hazard_2", "haz3");
-                move_option_weight(max_hazard, "hazard_3", "haz3");
-                move_option_weight(max_hazard, "hazard_4", "haz4");
-                move_option_weight(max_hazard, "hazard_5", "haz5");
-            }
-
-            if let Some(progression_diff) = game_hash.get_mut(&Yaml::from_str("progression_diff")) {
-                move_option_weight(progression_diff, "leaflover", "easy");
-            }
+        Some("CrossCode") => push_value_or_default(&mut notes, game_hash, triggers, "enable_dlc", "false"),
+        Some("Another Crabs Treasure") => push_value_or_default(&mut notes, game_hash, triggers, "logic_rules", "vanilla"),
+        Some("Keep Talking and Nobody Explodes") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "adventure_mode", "vanilla_vanguard");
         }
-        Some("Powerwash Simulator") => {
-            let mut dlc = vec![];
+        Some("La-Mulana 2") => {
+            game_hash.insert(Yaml::from_str("potsanity_low_value"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_high_value"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_shuriken"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_rolling_shuriken"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_earth_spear"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_flare"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_caltrops"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_chakram"), Yaml::Boolean(true));
+            game_hash.insert(Yaml::from_str("potsanity_bomb"), Yaml::Boolean(true));
 
-            for option in [
-                "midgar",
-                "tomb_raider",
-                "wallace_and_gromit_dlc",
-                "shrek_dlc",
-                "alice_in_wonderland_dlc",
-                "warhammer_40k_dlc",
-                "back_to_the_future_dlc",
-                "spongebob_dlc",
-            ] {
-                if game_hash.get(&Yaml::from_str(option)).is_some_and(|yaml| yaml.as_vec().is_none_or(|vec| !vec.is_empty())) {
-                    dlc.push(option);
+            push_value_or_default(&mut notes, game_hash, triggers, "oannesanity", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_difficulty", "normal");
+            push_value_or_default(&mut notes, game_hash, triggers, "costume_clip", "false");
+            push_value_or_default(&mut notes, game_hash, triggers, "dlc_item_logic", "false");
+        }
+        Some("UNBEATABLE Arcade") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "use_dlc", "[]");
+        }
+        Some("Yellow Taxi Goes Vroom") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "include_out_of_bounds", "none");
+        }
+        Some("The Grinch") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "advanced_logic", "false");
+        }
+        Some("ULTRAKILL") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "speedrunner_logic", "false");
+        }
+        // IGNORE
+        Some("Dark Souls Remastered") => {
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_to_access_catacombs", "ornstein_and_smough");
+            push_value_or_default(&mut notes, game_hash, triggers, "logic_to_access_totg", "skull_lantern");
+        }
+        Some("Elementipelago") => {
+            let key = Yaml::from_str("start_inventory");
+            if let Some(start_inventory) = game_hash.get_mut(&key) {
+                if let Some(hash) = start_inventory.as_mut_hash() {
+                    hash.insert(Yaml::from_str("Filters"), Yaml::Integer(1));
                 }
-            }
-
-            if dlc.is_empty() {
-                notes.push(String::from("dlc: none"));
             } else {
-                notes.push(format!("dlc: [{}]", dlc.join(", ")));
+                let mut hash = LinkedHashMap::new();
+                hash.insert(Yaml::from_str("Filters"), Yaml::Integer(1));
+                game_hash.insert(key, Yaml::Hash(hash));
             }
         }
-        Some("TCG Card Shop Simulator") => {
-            if let Some(extra_starting_item_checks) = game_hash.get_mut(&Yaml::from_str("extra_starting_item_checks")) {
-                move_option_weight_matches(
-                    extra_starting_item_checks,
-                    |yaml| yaml.as_i64().is_some_and(|val| val < 5) || yaml.as_f64().is_some_and(|val| val < 5.0) || yaml.as_str().is_some_and(|val| val.parse::<f64>().is_ok_and(|val| val < 5.0)),
-                    "easy",
-                );
+        _ => (),
+    };
+
+    notes
+}
+
+// brings options from older versions of a game up to date, without rolling or adding anything the yaml didn't ask for
+fn migrate_game_options(game_hash: &mut LinkedHashMap<Yaml, Yaml>, game: &Yaml) {
+    derive_game_options(game_hash, game);
+    rename_game_options(game_hash, game);
+}
+
+// options and values that were only renamed, which is all a trigger can be migrated with as it only sees the options it names
+fn rename_game_options(game_hash: &mut LinkedHashMap<Yaml, Yaml>, game: &Yaml) {
+    match game.as_str() {
+        Some("OpenRCT2") => {
+            if let Some(mut awards) = game_hash.remove(&Yaml::from_str("awards")) {
+                move_option_weight(&mut awards, "false", "none");
+                move_option_weight(&mut awards, "true", "all");
+                game_hash.insert(Yaml::from_str("selected_awards"), awards);
+            }
+            if let Some(food_poisioning_traps) = game_hash.remove(&Yaml::from_str("food_poisioning_traps")) {
+                game_hash.insert(Yaml::from_str("food_poisoning_traps"), food_poisioning_traps);
             }
         }
-        Some("Yu-Gi-Oh! Dungeon Dice Monsters") => {
-            game_hash.remove(&Yaml::from_str("duelist_rematches"));
+        Some("Stardew Valley") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "perfection", "random");
+            }
+
+            if let Some(entrance_randomization) = game_hash.get_mut(&Yaml::from_str("entrance_randomization")) {
+                move_option_weight(entrance_randomization, "chaos", "disabled");
+            }
+
+            change_option_name(game_hash, "trap_items", "trap_difficulty");
         }
-        // IGNORE
-        Some("Nodebuster") => {
-            if let Some(progressive_items) = game_hash.remove(&Yaml::from_str("progressiveItems")) {
-                game_hash.insert(Yaml::from_str("progressive_items"), progressive_items);
+        Some("Overcooked! 2") => {
+            if let Some(star_threshold_scale) = game_hash.get_mut(&Yaml::from_str("star_threshold_scale")) {
+                move_option_weight_matches(star_threshold_scale, |yaml| as_i64(yaml).is_some_and(|v| v >= 90), "random-range-50-90");
             }
         }
-        Some("Iji") => {
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "normal_logic");
+        Some("Final Fantasy 12 Open World") => {
+            if let Some(character_progression_scaling) = game_hash.remove(&Yaml::from_str("character_progression_scaling")) {
+                game_hash.insert(Yaml::from_str("difficulty_progressive_scaling"), character_progression_scaling);
+            }
         }
-        Some("Rift of the Necrodancer") => {
-            push_value_or_default(&mut notes, game_hash, "dlc_songs", "[]");
+        Some("Kingdom Hearts") => {
+            rename_true_false(game_hash, "cups", "cups", "off");
+
+            if let Some(level_checks) = game_hash.get_mut(&Yaml::from_str("level_checks")) {
+                move_option_weight(level_checks, "100", "99");
+            }
+
+            if let Some(force_stats_on_levels) = game_hash.get_mut(&Yaml::from_str("force_stats_on_levels")) {
+                move_option_weight(force_stats_on_levels, "1", "2");
+            }
         }
-        Some("Ship of Harkinian") => {
-            rename_true_false(game_hash, "shuffle_scrubs", "one_time_only", "off");
+        Some("Starcraft 2") => {
+            if let Some(mission_order) = game_hash.get_mut(&Yaml::from_str("mission_order")) {
+                move_option_weight(mission_order, "tiny_grid", "grid");
+                move_option_weight(mission_order, "mini_grid", "grid");
+                move_option_weight(mission_order, "medium_grid", "grid");
+                move_option_weight(mission_order, "mini_gauntlet", "gauntlet");
+            }
 
-            if let Some(maps_and_compasses) = game_hash.get_mut(&Yaml::from_str("maps_and_compasses")) {
-                move_option_weight(maps_and_compasses, "shuffle", "anywhere");
+            if let Some(kerrigan_presence) = game_hash.get_mut(&Yaml::from_str("kerrigan_presence")) {
+                move_option_weight(kerrigan_presence, "not_present_and_no_passives", "not_present");
+                move_option_weight(kerrigan_presence, "kerrigan_max_passive_abilities", "0");
             }
 
-            if let Some(shuffle_dungeon_rewards) = game_hash.get_mut(&Yaml::from_str("shuffle_dungeon_rewards")) {
-                move_option_weight(shuffle_dungeon_rewards, "dungeons", "any_dungeon");
+            if let Some(spear_of_adun_presence) = game_hash.get_mut(&Yaml::from_str("spear_of_adun_presence")) {
+                move_option_weight(spear_of_adun_presence, "lotv_protoss", "vanilla");
             }
 
-            game_hash.remove(&Yaml::from_str("skip_ganons_trials"));
+            rename_true_false(game_hash, "grant_story_tech", "grant", "no_grant");
 
-            if option_can_be(game_hash, "key_rings", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
-                game_hash.insert(Yaml::from_str("key_rings_count"), Yaml::Integer(9));
+            if let Some(vanilla_locations) = game_hash.get_mut(&Yaml::from_str("vanilla_locations")) {
+                move_option_weight(vanilla_locations, "resources", "filler");
             }
 
-            rename_true_false(game_hash, "key_rings", "count", "off");
-            rename_true_false(game_hash, "bombchu_bag", "single_bag", "none");
+            if let Some(extra_locations) = game_hash.get_mut(&Yaml::from_str("extra_locations")) {
+                move_option_weight(extra_locations, "resources", "filler");
+            }
+
+            if let Some(challenge_locations) = game_hash.get_mut(&Yaml::from_str("challenge_locations")) {
+                move_option_weight(challenge_locations, "resources", "filler");
+            }
+
+            if let Some(mastery_locations) = game_hash.get_mut(&Yaml::from_str("mastery_locations")) {
+                move_option_weight(mastery_locations, "resources", "filler");
+            }
 
-            push_value_or_default(&mut notes, game_hash, "enable_all_tricks", "false");
-            push_value_or_default(&mut notes, game_hash, "tricks_in_logic", "[]");
+            if let Some(grid_two_start_positions) = game_hash.remove(&Yaml::from_str("grid_two_start_positions")) {
+                game_hash.insert(Yaml::from_str("two_start_positions"), grid_two_start_positions);
+            }
         }
-        Some("Forged Curse") => {
-            push_value_or_default(&mut notes, game_hash, "barrier_skip", "false");
-            push_value_or_default(&mut notes, game_hash, "damage_boost", "false");
+        Some("A Link Between Worlds") => {
+            rename_true_false(game_hash, "super_items", "shuffled", "off");
         }
-        Some("An Untitled Story") => push_value_or_default(&mut notes, game_hash, "hard_logic", "false"),
-        Some("Portal 2") => {
-            rename_true_false(game_hash, "open_world", "open_world", "normal");
-            if let Some(open_world) = game_hash.remove(&Yaml::from_str("open_world")) {
-                game_hash.insert(Yaml::from_str("game_mode"), open_world);
+        Some("Banjo-Tooie") => {
+            if let Some(randomize_world_entrance_loading_zone) = game_hash.remove(&Yaml::from_str("randomize_world_entrance_loading_zone")) {
+                game_hash.insert(Yaml::from_str("randomize_world_entrance_loading_zones"), randomize_world_entrance_loading_zone);
             }
-            if let Some(cutscenelevels) = game_hash.remove(&Yaml::from_str("cutscenelevels")) {
-                game_hash.insert(Yaml::from_str("cutscene_levels"), cutscenelevels);
+
+            if let Some(randomize_boss_loading_zone) = game_hash.remove(&Yaml::from_str("randomize_boss_loading_zone")) {
+                game_hash.insert(Yaml::from_str("randomize_boss_loading_zones"), randomize_boss_loading_zone);
             }
-            if let Some(wheatleymonitors) = game_hash.remove(&Yaml::from_str("wheatleymonitors")) {
-                game_hash.insert(Yaml::from_str("wheatley_monitors"), wheatleymonitors);
+            change_option_name(game_hash, "game_length", "world_requirements");
+            if let Some(open_silos) = game_hash.get_mut(&Yaml::from_str("open_silos")) {
+                move_option_weight(open_silos, "none", "1");
+                move_option_weight(open_silos, "one", "2");
+                move_option_weight(open_silos, "all", "7");
             }
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "normal");
         }
-        Some("Cave Story") => rename_true_false(game_hash, "early_weapon", "good_weapons", "none"),
-        Some("Star Wars Episode I Racer") => {
-            resolve_weighted_option(game_hash, "progressive_circuits");
-            if let Some(progressive_circuits) = game_hash.remove(&Yaml::from_str("progressive_circuits"))
-                && let Some(course_unlock_mode) = game_hash.get_mut(&Yaml::from_str("course_unlock_mode"))
-            {
-                if course_unlock_mode.as_str().is_some_and(|str| str == "circuit_pass" || str == "circuit_pass_invitational") {
-                    match (progressive_circuits.as_str(), progressive_circuits.as_bool()) {
-                        (Some("true"), _) | (_, Some(true)) => {
-                            *course_unlock_mode = Yaml::from_str("progressive_circuits");
-                        }
-                        (Some("false"), _) | (_, Some(false)) => {
-                            *course_unlock_mode = Yaml::from_str("circuits");
-                        }
-                        _ => (),
+        Some("Ori and the Will of the Wisps") => {
+            if let Some(spawn) = game_hash.get_mut(&Yaml::from_str("spawn")) {
+                move_option_weight(spawn, "marsh", "vanilla");
+            }
+            rename_true_false(game_hash, "door_rando", "coupled", "disabled");
+        }
+        Some("Pokemon FireRed and LeafGreen") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "elite_four", "champion");
+                move_option_weight(goal, "elite_four_rematch", "champion_rematch");
+            }
+
+            rename_true_false(game_hash, "trainersanity", "456", "0");
+            rename_true_false(game_hash, "provide_hints", "all", "off");
+            rename_true_false(game_hash, "flash_required", "required", "off");
+            rename_true_false(game_hash, "randomize_fly_destinations", "completely_random", "off");
+
+            if let Some(dungeon_entrance_shuffle) = game_hash.remove(&Yaml::from_str("dungeon_entrance_shuffle")) {
+                game_hash.insert(Yaml::from_str("shuffle_dungeons"), dungeon_entrance_shuffle);
+            }
+
+            if let Some(mut shuffle_ledge_jump) = game_hash.remove(&Yaml::from_str("shuffle_ledge_jump")) {
+                move_option_weight(&mut shuffle_ledge_jump, "false", "off");
+                move_option_weight(&mut shuffle_ledge_jump, "true", "full");
+                game_hash.insert(Yaml::from_str("shuffle_dropdowns"), shuffle_ledge_jump);
+            }
+        }
+        Some("Slay the Spire") => {
+            if let Some(character) = game_hash.remove(&Yaml::from_str("character")) {
+                game_hash.insert(Yaml::from_str("characters"), character);
+            }
+        }
+        Some("Super Metroid Map Rando") => {
+            rename_true_false(game_hash, "transition_letters", "letters", "arrows");
+        }
+        Some("Sonic Adventure DX") => {
+            rename_true_false(game_hash, "lazy_fishing", "enabled_all", "disabled");
+        }
+        Some("Paper Mario") => rename_true_false(game_hash, "super_multi_blocks", "anywhere", "off"),
+        Some("Gauntlet Legends") => {
+            if let Some(traps_frequency) = game_hash.get_mut(&Yaml::from_str("traps_frequency")) {
+                move_option_weight(traps_frequency, "normal", "10");
+                move_option_weight(traps_frequency, "large", "15");
+                move_option_weight(traps_frequency, "extreme", "50");
+            }
+
+            if let Some(obelisks) = game_hash.get_mut(&Yaml::from_str("obelisks")) {
+                move_option_weight(obelisks, "none", "false");
+                move_option_weight(obelisks, "all_obelisks", "true");
+            }
+
+            if let Some(mirror_shards) = game_hash.get_mut(&Yaml::from_str("mirror_shards")) {
+                move_option_weight(mirror_shards, "none", "false");
+                move_option_weight(mirror_shards, "all_shards", "true");
+            }
+
+            if let Some(max_difficulty_value) = game_hash.remove(&Yaml::from_str("max_difficulty_value")) {
+                game_hash.insert(Yaml::from_str("max_difficulty"), max_difficulty_value);
+            }
+        }
+        Some("Pokemon Crystal") => {
+            rename_true_false(game_hash, "enable_mischief", "mild", "off");
+
+            if let Some(learnset_type_bias) = game_hash.get_mut(&Yaml::from_str("learnset_type_bias")) {
+                move_option_weight(learnset_type_bias, "vanilla", "none");
+            }
+
+            rename_true_false(game_hash, "require_itemfinder", "hard_required", "not_required");
+            rename_true_false(game_hash, "randomize_wilds", "completely_random", "vanilla");
+            rename_true_false(game_hash, "randomize_music", "completely_random", "off");
+        }
+        Some("The Witness") => {
+            if let Some(elevators_come_to_you) = game_hash.get_mut(&Yaml::from_str("elevators_come_to_you")) {
+                move_option_weight_to_yaml(
+                    elevators_come_to_you,
+                    "true",
+                    Yaml::Array(vec![Yaml::from_str("Quarry Elevator"), Yaml::from_str("Swamp Long Bridge"), Yaml::from_str("Bunker Elevator")]),
+                );
+                move_option_weight_to_yaml(elevators_come_to_you, "false", Yaml::Hash(LinkedHashMap::new()));
+            }
+        }
+        Some("Final Fantasy Tactics Advance") => rename_true_false(game_hash, "progressive_shop_battle_unlock", "enabled", "disabled"),
+        Some("Rain World") => {
+            if let Some(which_victory_condition) = game_hash.get_mut(&Yaml::from_str("which_victory_condition")) {
+                move_option_weight(which_victory_condition, "alternate", "echoes");
+                move_option_weight(which_victory_condition, "weaver", "ascension");
+            }
+
+            if let Some(checks_spread_rot) = game_hash.get_mut(&Yaml::from_str("checks_spread_rot")) {
+                move_option_weight(checks_spread_rot, "off", "false");
+                move_option_weight(checks_spread_rot, "on", "true");
+                move_option_weight(checks_spread_rot, "prince_ending_only", "related_ending_only");
+            }
+        }
+        Some("Psychonauts") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("Goal")) {
+                move_option_weight(goal, "braintank_and_brainhunt", "asylum_brain_tank_and_brain_hunt");
+            }
+        }
+        Some("Luigi's Mansion") => {
+            rename_true_false(game_hash, "door_rando", "randomized", "off");
+        }
+        Some("Paper Mario The Thousand Year Door") => {
+            if let Some(starting_partner) = game_hash.get_mut(&Yaml::from_str("starting_partner")) {
+                move_option_weight(starting_partner, "from_partner", "random");
+            }
+
+            if let Some(yoshi_color) = game_hash.get_mut(&Yaml::from_str("yoshi_color")) {
+                move_option_weight(yoshi_color, "random_color", "random");
+            }
+
+            rename_true_false(game_hash, "star_shuffle", "all", "vanilla");
+        }
+        Some("Golden Sun The Lost Age") => {
+            if let Some(enemy_elemental_resistance) = game_hash.get_mut(&Yaml::from_str("enemy_elemental_resistance")) {
+                move_option_weight(enemy_elemental_resistance, "shuffle_elemmental_res", "shuffle_elemental_res");
+            }
+        }
+        Some("Minishoot Adventures") => {
+            if let Some(completion_goals) = game_hash.get_mut(&Yaml::from_str("completion_goals")) {
+                move_option_weight(completion_goals, "both", "dungeon_5_and_snow");
+            }
+        }
+        Some("Stacklands") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "kill_demon", "random_boss");
+                move_option_weight(goal, "kill_wicked_witch", "random_boss");
+            }
+        }
+        Some("Metroid: Zero Mission") => {
+            if let Some(walljumps_in_logic) = game_hash.remove(&Yaml::from_str("walljumps_in_logic")) {
+                game_hash.insert(Yaml::from_str("walljumps"), walljumps_in_logic);
+            }
+
+            rename_true_false(game_hash, "walljumps", "enabled", "disabled");
+            rename_true_false(game_hash, "hazard_runs", "normal", "disabled");
+        }
+        Some("Cuphead") => {
+            rename_true_false(game_hash, "dlc_boss_chalice_checks", "enabled", "disabled");
+            rename_true_false(game_hash, "dlc_rungun_chalice_checks", "enabled", "disabled");
+            rename_true_false(game_hash, "dlc_kingdice_chalice_checks", "enabled", "disabled");
+            rename_true_false(game_hash, "dlc_chess_chalice_checks", "enabled", "disabled");
+            rename_true_false(game_hash, "level_shuffle", "enabled", "disabled");
+        }
+        Some("Metroid Fusion") => {
+            if let Some(tricky_shinesparks_in_region_logic) = game_hash.remove(&Yaml::from_str("TrickyShinesparksInRegionLogic")) {
+                game_hash.insert(Yaml::from_str("ShinesparkTrickDifficulty"), tricky_shinesparks_in_region_logic);
+            }
+        }
+        Some("Simpsons Hit and Run") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "goal: all missions complete!", "goal_all_missions_complete");
+                move_option_weight(goal, "goal: all story missions complete!", "goal_all_story_missions_complete");
+                move_option_weight(goal, "goal: final mission(l7m7)", "goal_final_missionl7m7");
+                move_option_weight(goal, "goal: wasps and cards collected!", "goal_wasps_and_cards_collected");
+            }
+        }
+        Some("Satisfactory") => {
+            if let Some(mut final_elevator_package) = game_hash.remove(&Yaml::from_str("final_elevator_package")) {
+                move_option_weight(&mut final_elevator_package, "one package (tiers 1-2)", "phase 1 (tiers 1-2)");
+                move_option_weight(&mut final_elevator_package, "two packages (tiers 1-4)", "phase 2 (tiers 1-4");
+                move_option_weight(&mut final_elevator_package, "three packages (tiers 1-6)", "phase 3 (tiers 1-6)");
+                move_option_weight(&mut final_elevator_package, "four packages (tiers 1-8)", "phase 3 (tiers 1-6)");
+                move_option_weight(&mut final_elevator_package, "five packages (tiers 1-9)", "phase 3 (tiers 1-6)");
+                move_option_weight(&mut final_elevator_package, "phase 4 (tiers 1-8)", "phase 3 (tiers 1-6)");
+                move_option_weight(&mut final_elevator_package, "phase 5 (tiers 1-9)", "phase 3 (tiers 1-6)");
+                move_option_weight(&mut final_elevator_package, "4", "3");
+                move_option_weight(&mut final_elevator_package, "5", "3");
+                move_option_weight(&mut final_elevator_package, "random", "random-range-1-3");
+                move_option_weight(&mut final_elevator_package, "random-low", "random-low-range-1-3");
+                move_option_weight(&mut final_elevator_package, "random-high", "random-high-range-1-3");
+                game_hash.insert(Yaml::from_str("final_elevator_phase"), final_elevator_package);
+            }
+        }
+        Some("Trackmania") => {
+            if let Some(disable_author) = game_hash.remove(&Yaml::from_str("disable_author")) {
+                game_hash.insert(Yaml::from_str("disable_author_locations"), disable_author);
+            }
+        }
+        Some("The Legend of Zelda - Phantom Hourglass") => {
+            rename_true_false(game_hash, "randomize_harrow", "randomize_without_hints", "no_harrow");
+
+            if let Some(goal_requirements) = game_hash.get_mut(&Yaml::from_str("goal_requirements")) {
+                move_option_weight(goal_requirements, "complete_dungeons", "defeat_bosses");
+            }
+
+            rename_true_false(game_hash, "shuffle_dungeon_entrances", "shuffle", "no_shuffle");
+
+            if let Some(additional_metal_names) = game_hash.get_mut(&Yaml::from_str("additional_metal_names")) {
+                move_option_weight(additional_metal_names, "custom_unique", "custom_prefer_vanilla");
+            }
+        }
+        Some("Astalon") => rename_true_false(game_hash, "fast_blood_chalice", "always", "off"),
+        Some("Anodyne") => {
+            if let Some(red_cave_access) = game_hash.remove(&Yaml::from_str("red_cave_access")) {
+                game_hash.insert(Yaml::from_str("red_grotto_access"), red_cave_access);
+            }
+        }
+        Some("Ape Escape 3") => {
+            if let Some(goal_target_override) = game_hash.get_mut(&Yaml::from_str("goal_target_override")) {
+                move_option_weight(goal_target_override, "disable", "1");
+            }
+        }
+        Some("Ape Escape") => {
+            if let Some(entrance) = game_hash.get_mut(&Yaml::from_str("entrance")) {
+                move_option_weight(entrance, "on", "recommended");
+                move_option_weight(entrance, "lockmm", "recommended");
+            }
+        }
+        Some("Super Mario Land 2") => {
+            if let Some(mario_coin_fragment_percentage) = game_hash.get_mut(&Yaml::from_str("mario_coin_fragment_percentage")) {
+                move_option_weight_matches(mario_coin_fragment_percentage, |yaml| as_i64(yaml).is_some_and(|v| v >= 50), "50");
+            }
+        }
+        Some("Donkey Kong 64") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "krool", "acquire_key_8");
+                move_option_weight(goal, "all_keys", "acquire_key_8");
+            }
+        }
+        Some("Mario Kart 64") => {
+            if let Some(logic_difficulty) = game_hash.get_mut(&Yaml::from_str("logic_difficulty")) {
+                move_option_weight(logic_difficulty, "generous", "basic");
+            }
+        }
+        Some("Crystal Project") => {
+            rename_true_false(game_hash, "regionsanity", "enabled", "disabled");
+            rename_true_false(game_hash, "shopsanity", "enabled", "disabled");
+            rename_true_false(game_hash, "levelGating", "level_set", "none");
+
+            for (old, new) in [
+                ("trapLikelihood", "trap_likelihood"),
+                ("clamshellGoalQuantity", "clamshell_goal_quantity"),
+                ("extraClamshellsInPool", "extra_clamshells_in_pool"),
+                ("newWorldStoneJobQuantity", "new_world_stone_job_quantity"),
+                ("includedRegions", "included_regions"),
+                ("jobRando", "job_rando"),
+                ("startingJobQuantity", "starting_job_quantity"),
+                ("killBossesMode", "kill_bosses_mode"),
+                ("progressiveMountMode", "progressive_mount_mode"),
+                ("levelGating", "level_gating"),
+                ("levelComparedToEnemies", "level_compared_to_enemies"),
+                ("progressiveLevelSize", "progressive_level_size"),
+                ("maxLevel", "max_level"),
+                ("keyMode", "key_mode"),
+                ("obscureRoutes", "obscure_routes"),
+                ("easyLeveling", "easy_leveling"),
+                ("progressiveEquipmentMode", "progressive_equipment_mode"),
+                ("startWithTreasureFinder", "start_with_treasure_finder"),
+                ("startWithMaps", "start_with_maps"),
+                ("includeSummonAbilities", "include_summon_abilities"),
+                ("includeScholarAbilities", "include_scholar_abilities"),
+                ("randomizeMusic", "randomize_music"),
+                ("useMods", "use_mods"),
+            ] {
+                if let Some(value) = game_hash.remove(&Yaml::from_str(old)) {
+                    game_hash.insert(Yaml::from_str(new), value);
+                }
+            }
+        }
+        Some("Rabbit and Steel") => {
+            if let Some(run_type) = game_hash.get_mut(&Yaml::from_str("run_type")) {
+                move_option_weight(run_type, "chaotic", "combined");
+            }
+        }
+        Some("PokePark") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "aftergame", "postgame");
+            }
+        }
+        Some("Diddy Kong Racing") => {
+            if let Some(mut mirrored_tracks) = game_hash.remove(&Yaml::from_str("mirrored_tracks")) {
+                move_option_weight(&mut mirrored_tracks, "vanilla", "adventure_1");
+                move_option_weight(&mut mirrored_tracks, "mirrored", "adventure_2");
+                game_hash.insert(Yaml::from_str("track_version"), mirrored_tracks);
+            }
+        }
+        Some("The Binding of Isaac Repentance") => {
+            if let Some(mut split_start_items) = game_hash.remove(&Yaml::from_str("split_start_items")) {
+                move_option_weight(&mut split_start_items, "off", "false");
+                move_option_weight_matches(&mut split_start_items, |yaml| yaml.as_str().is_none_or(|str| str != "off"), "true");
+                game_hash.insert(Yaml::from_str("scatter_previous_items"), split_start_items);
+            }
+
+            if let Some(custom_junk_item_weights) = game_hash.remove(&Yaml::from_str("custom_junk_item_weights")) {
+                game_hash.insert(Yaml::from_str("junk_weights"), custom_junk_item_weights);
+            }
+
+            if let Some(trap_item_weights) = game_hash.remove(&Yaml::from_str("trap_item_weights")) {
+                game_hash.insert(Yaml::from_str("trap_weights"), trap_item_weights);
+            }
+
+            if let Some(mut fortunes_are_hints) = game_hash.remove(&Yaml::from_str("fortunes_are_hints")) {
+                move_option_weight(&mut fortunes_are_hints, "false", "0");
+                move_option_weight(&mut fortunes_are_hints, "true", "100");
+
+                game_hash.insert(Yaml::from_str("fortune_machine_hint_percentage"), fortunes_are_hints);
+            }
+        }
+        Some("Kirby Super Star") => {
+            if let Some(kirby_flavor_preset) = game_hash.get_mut(&Yaml::from_str("kirby_flavor_preset")) {
+                move_option_weight(kirby_flavor_preset, "custom", "default");
+            }
+        }
+        Some("Kirby 64 - The Crystal Shards") => {
+            if let Some(total_crystals) = game_hash.remove(&Yaml::from_str("total_crystals")) {
+                game_hash.insert(Yaml::from_str("max_crystals"), total_crystals);
+            }
+        }
+        Some("Deep Rock Galactic") => {
+            if let Some(max_hazard) = game_hash.get_mut(&Yaml::from_str("max_hazard")) {
+                move_option_weight(max_hazard, "hazard_1", "haz3");
+                move_option_weight(max_hazard, "hazard_2", "haz3");
+                move_option_weight(max_hazard, "hazard_3", "haz3");
+                move_option_weight(max_hazard, "hazard_4", "haz4");
+                move_option_weight(max_hazard, "hazard_5", "haz5");
+            }
+
+            if let Some(progression_diff) = game_hash.get_mut(&Yaml::from_str("progression_diff")) {
+                move_option_weight(progression_diff, "leaflover", "easy");
+            }
+        }
+        Some("TCG Card Shop Simulator") => {
+            if let Some(extra_starting_item_checks) = game_hash.get_mut(&Yaml::from_str("extra_starting_item_checks")) {
+                move_option_weight_matches(
+                    extra_starting_item_checks,
+                    |yaml| yaml.as_i64().is_some_and(|val| val < 5) || yaml.as_f64().is_some_and(|val| val < 5.0) || yaml.as_str().is_some_and(|val| val.parse::<f64>().is_ok_and(|val| val < 5.0)),
+                    "easy",
+                );
+            }
+        }
+        // IGNORE
+        Some("Nodebuster") => {
+            if let Some(progressive_items) = game_hash.remove(&Yaml::from_str("progressiveItems")) {
+                game_hash.insert(Yaml::from_str("progressive_items"), progressive_items);
+            }
+        }
+        Some("Ship of Harkinian") => {
+            rename_true_false(game_hash, "shuffle_scrubs", "one_time_only", "off");
+
+            if let Some(maps_and_compasses) = game_hash.get_mut(&Yaml::from_str("maps_and_compasses")) {
+                move_option_weight(maps_and_compasses, "shuffle", "anywhere");
+            }
+
+            if let Some(shuffle_dungeon_rewards) = game_hash.get_mut(&Yaml::from_str("shuffle_dungeon_rewards")) {
+                move_option_weight(shuffle_dungeon_rewards, "dungeons", "any_dungeon");
+            }
+
+            rename_true_false(game_hash, "key_rings", "count", "off");
+            rename_true_false(game_hash, "bombchu_bag", "single_bag", "none");
+        }
+        Some("Portal 2") => {
+            rename_true_false(game_hash, "open_world", "open_world", "normal");
+            if let Some(open_world) = game_hash.remove(&Yaml::from_str("open_world")) {
+                game_hash.insert(Yaml::from_str("game_mode"), open_world);
+            }
+            if let Some(cutscenelevels) = game_hash.remove(&Yaml::from_str("cutscenelevels")) {
+                game_hash.insert(Yaml::from_str("cutscene_levels"), cutscenelevels);
+            }
+            if let Some(wheatleymonitors) = game_hash.remove(&Yaml::from_str("wheatleymonitors")) {
+                game_hash.insert(Yaml::from_str("wheatley_monitors"), wheatleymonitors);
+            }
+        }
+        Some("Cave Story") => rename_true_false(game_hash, "early_weapon", "good_weapons", "none"),
+        Some("Donkey Kong Toluca") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "kore", "kastle_kaos");
+                move_option_weight(goal, "krematoa", "knautilus");
+            }
+
+            if let Some(kong_checks) = game_hash.remove(&Yaml::from_str("kong_checks")) {
+                game_hash.insert(Yaml::from_str("kong_locations"), kong_checks);
+            }
+
+            if let Some(dk_coin_checks) = game_hash.remove(&Yaml::from_str("dk_coin_checks")) {
+                game_hash.insert(Yaml::from_str("dk_coin_locations"), dk_coin_checks);
+            }
+
+            if let Some(balloon_checks) = game_hash.remove(&Yaml::from_str("balloon_checks")) {
+                game_hash.insert(Yaml::from_str("balloon_locations"), balloon_checks);
+            }
+
+            if let Some(banana_checks) = game_hash.remove(&Yaml::from_str("banana_checks")) {
+                game_hash.insert(Yaml::from_str("banana_locations"), banana_checks);
+            }
+
+            if let Some(coin_checks) = game_hash.remove(&Yaml::from_str("coin_checks")) {
+                game_hash.insert(Yaml::from_str("coin_locations"), coin_checks);
+            }
+
+            if let Some(bird_checks) = game_hash.remove(&Yaml::from_str("bird_checks")) {
+                game_hash.insert(Yaml::from_str("bird_locations"), bird_checks);
+            }
+        }
+        Some("APBingo") => {
+            if let Some(board_size) = game_hash.get_mut(&Yaml::from_str("board_size")) {
+                move_option_weight(board_size, "3", "4");
+            }
+        }
+        Some("Aquaria") => rename_true_false(game_hash, "light_needed_to_get_to_dark_places", "on", "off"),
+        Some("Pokemon Stadium") => {
+            if let Some(victory_condition) = game_hash.get_mut(&Yaml::from_str("VictoryCondition")) {
+                move_option_weight(victory_condition, "clear_master_ball_cup", "clear_master_ball_cups");
+            }
+        }
+        Some("shapez") => {
+            if let Some(goal_amount) = game_hash.get_mut(&Yaml::from_str("goal_amount")) {
+                move_option_weight(goal_amount, "random", "random-range-1-100");
+                move_option_weight_matches(
+                    goal_amount,
+                    |yaml| yaml.as_i64().is_some_and(|v| v > 100) || yaml.as_str().is_some_and(|str| str.parse::<i64>().is_ok_and(|v| v > 100)),
+                    "100",
+                );
+            }
+        }
+        // IGNORE
+        Some("Slime Rancher 2") => {
+            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
+                move_option_weight(goal, "prismacore_enter", "prismacore");
+                move_option_weight(goal, "prismacore_stabilize", "prismacore");
+            }
+        }
+        Some("Hammerwatch") => {
+            rename_true_false(game_hash, "randomize_enemy_loot", "on", "off");
+            if let Some(key_mode) = game_hash.get_mut(&Yaml::from_str("key_mode")) {
+                move_option_weight(key_mode, "vanilla", "generic");
+            }
+        }
+        Some("Lego Star Wars: The Complete Saga") => {
+            if let Some(chapter_unlock_requirement) = game_hash.get_mut(&Yaml::from_str("chapter_unlock_requirement")) {
+                move_option_weight(chapter_unlock_requirement, "story_characters", "vanilla_characters");
+            }
+        }
+        // IGNORE
+        Some("Backlog Expedition") => {
+            if let Some(beaten_to_goal) = game_hash.remove(&Yaml::from_str("beaten_to_goal")) {
+                game_hash.insert(Yaml::from_str("treasures_to_goal"), beaten_to_goal);
+            }
+        }
+        Some("ULTRAKILL") => {
+            rename_true_false(game_hash, "randomize_secondary_fire", "split", "disabled");
+        }
+        Some("Mega Man X2") => {
+            if let Some(pickupsanity) = game_hash.remove(&Yaml::from_str("pickupsanity")) {
+                game_hash.insert(Yaml::from_str("pickup_locations"), pickupsanity);
+            }
+            if let Some(starting_life_count) = game_hash.remove(&Yaml::from_str("starting_life_count")) {
+                game_hash.insert(Yaml::from_str("starting_hp"), starting_life_count);
+            }
+            if let Some(boss_weakness_rando) = game_hash.get_mut(&Yaml::from_str("boss_weakness_rando")) {
+                move_option_weight(boss_weakness_rando, "shuffled", "simple");
+            }
+            if let Some(x_hunters_medal_count) = game_hash.remove(&Yaml::from_str("x_hunters_medal_count")) {
+                game_hash.insert(Yaml::from_str("x_hunter_base_medal_count"), x_hunters_medal_count);
+            }
+            if let Some(mut base_open) = game_hash.remove(&Yaml::from_str("base_open")) {
+                move_option_weight(&mut base_open, "false", "item");
+                move_option_weight(&mut base_open, "true", "medals");
+                game_hash.insert(Yaml::from_str("x_hunter_base_open"), base_open);
+            }
+        }
+        Some("Skyward Sword") => {
+            if let Some(randomize_entrances) = game_hash.get_mut(&Yaml::from_str("randomize_entrances")) {
+                move_option_weight(randomize_entrances, "required_dungeons_separately", "required_dungeons_only");
+                move_option_weight(randomize_entrances, "all_surface_dungeons", "dungeons_only");
+            }
+        }
+        Some("Haste") => {
+            if let Some(shopsanity_seperate) = game_hash.remove(&Yaml::from_str("shopsanity_seperate")) {
+                game_hash.insert(Yaml::from_str("shopsanity_separate"), shopsanity_seperate);
+            }
+            if let Some(shopsanity_seperate_rate) = game_hash.remove(&Yaml::from_str("shopsanity_seperate_rate")) {
+                game_hash.insert(Yaml::from_str("shopsanity_separate_rate"), shopsanity_seperate_rate);
+            }
+            if let Some(permanent_items) = game_hash.remove(&Yaml::from_str("permanent_items")) {
+                game_hash.insert(Yaml::from_str("persistent_items"), permanent_items);
+            }
+            if let Some(permanent_item_quantities) = game_hash.remove(&Yaml::from_str("permanent_item_quantities")) {
+                game_hash.insert(Yaml::from_str("persistent_item_quantities"), permanent_item_quantities);
+            }
+        }
+        _ => (),
+    };
+}
+
+// options that were removed, split up or merged, or that other options now depend on; these run before the renames and see the old names and values
+fn derive_game_options(game_hash: &mut LinkedHashMap<Yaml, Yaml>, game: &Yaml) {
+    match game.as_str() {
+        Some("Stardew Valley") => {
+            if let Some(hatsanity) = game_hash.get_mut(&Yaml::from_str("hatsanity")).and_then(|yaml| yaml.as_mut_vec()) {
+                hatsanity.retain(|yaml| yaml.as_str().is_none_or(|str| str != "Near Perfection" && str != "Post Perfection"));
+            }
+        }
+        Some("osu!") => {
+            if let Some(minimum_difficulty) = game_hash.remove(&Yaml::from_str("minimum_difficulty")) {
+                game_hash.insert(Yaml::from_str("minimum_difficulty_standard"), minimum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("minimum_difficulty_catch"), minimum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("minimum_difficulty_taiko"), minimum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("minimum_difficulty_4k"), minimum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("minimum_difficulty_7k"), minimum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("minimum_difficulty_other_keys"), minimum_difficulty);
+            }
+
+            if let Some(maximum_difficulty) = game_hash.remove(&Yaml::from_str("maximum_difficulty")) {
+                game_hash.insert(Yaml::from_str("maximum_difficulty_standard"), maximum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("maximum_difficulty_catch"), maximum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("maximum_difficulty_taiko"), maximum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("maximum_difficulty_4k"), maximum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("maximum_difficulty_7k"), maximum_difficulty.clone());
+                game_hash.insert(Yaml::from_str("maximum_difficulty_other_keys"), maximum_difficulty);
+            }
+        }
+        Some("Mario & Luigi Superstar Saga") => {
+            game_hash.remove(&Yaml::from_str("harhall_pants"));
+        }
+        Some("Muse Dash") => {
+            game_hash.remove(&Yaml::from_str("available_trap_types"));
+        }
+        Some("TUNIC") => {
+            game_hash.remove(&Yaml::from_str("logic_rules"));
+            game_hash.remove(&Yaml::from_str("fixed_shop"));
+        }
+        Some("Ori and the Will of the Wisps") => {
+            game_hash.remove(&Yaml::from_str("regenerate_requirements"));
+        }
+        Some("Pokemon FireRed and LeafGreen") => {
+            game_hash.remove(&Yaml::from_str("shop_prices"));
+        }
+        Some("Against the Storm") => {
+            if let Some(enable_dlc) = game_hash.get(&Yaml::from_str("enable_dlc")).cloned() {
+                game_hash.insert(Yaml::from_str("enable_keepers_dlc"), enable_dlc.clone());
+                game_hash.insert(Yaml::from_str("enable_nightwatchers_dlc"), enable_dlc.clone());
+                game_hash.insert(Yaml::from_str("enable_biome_keys"), enable_dlc);
+            }
+        }
+        Some("Gauntlet Legends") => {
+            game_hash.remove(&Yaml::from_str("max_difficulty_toggle"));
+        }
+        Some("Rain World") => {
+            game_hash.remove(&Yaml::from_str("which_game_version"));
+
+            if option_can_be(game_hash, "which_victory_condition", &Yaml::from_str(""), &Yaml::from_str("weaver"))
+                || option_can_be(game_hash, "which_victory_condition", &Yaml::from_str(""), &Yaml::from_str("ascension"))
+            {
+                game_hash.insert(Yaml::from_str("randomize_weaver"), Yaml::Boolean(true));
+            }
+        }
+        Some("Sentinels of the Multiverse") => {
+            if let Some(filler_weights) = game_hash.get_mut(&Yaml::from_str("filler_weights")).and_then(|yaml| yaml.as_mut_vec()) {
+                for entry in filler_weights {
+                    if let Some(hash) = entry.as_mut_hash() {
+                        hash.remove(&Yaml::from_str("typed"));
+                    }
+                }
+            }
+            if let Some(pool_size) = game_hash.get_mut(&Yaml::from_str("pool_size")).and_then(|yaml| yaml.as_mut_hash()) {
+                for (_, v) in pool_size {
+                    if let Some(str) = v.as_str()
+                        && let Some((val, _)) = str.split_once('+')
+                    {
+                        *v = Yaml::from_str(val);
                     }
+                }
+            }
+            if let Some(location_density) = game_hash.get_mut(&Yaml::from_str("location_density")).and_then(|yaml| yaml.as_mut_hash()) {
+                let key = Yaml::from_str("hero");
+                if !location_density.contains_key(&key) {
+                    location_density.insert(key, Yaml::Integer(0));
+                }
+                if let Some(value) = location_density.remove(&Yaml::from_str("variant")) {
+                    location_density.insert(Yaml::from_str("variant_unlock"), value);
                 } else {
-                    *course_unlock_mode = Yaml::from_str("full_shuffle");
+                    location_density.insert(Yaml::from_str("variant_unlock"), Yaml::Integer(0));
                 }
             }
         }
-        Some("Donkey Kong Country 2") => push_value_or_default(&mut notes, game_hash, "logic", "strict"),
-        Some("Donkey Kong Toluca") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "kore", "kastle_kaos");
-                move_option_weight(goal, "krematoa", "knautilus");
+        Some("Hatsune Miku Project Diva Mega Mix+") => {
+            game_hash.remove(&Yaml::from_str("exclude_singers"));
+        }
+        Some("Refunct") => {
+            if let Some(hash) = game_hash.get_mut(&Yaml::from_str("minigames_likeliness")).and_then(|yaml| yaml.as_mut_hash()) {
+                for minigame in &["Climb Narrow Minigame", "Clique", "Funny Bridge Game Minigame", "Rando Mountain Minigame"] {
+                    let key = Yaml::from_str(minigame);
+                    if !hash.contains_key(&key) {
+                        hash.insert(key, Yaml::Integer(0));
+                    }
+                }
             }
+        }
+        Some("Jigsaw") => {
+            game_hash.remove(&Yaml::from_str("permillage_of_checks_out_of_logic"));
+            game_hash.remove(&Yaml::from_str("maximum_number_of_real_items"));
+            game_hash.remove(&Yaml::from_str("minimum_number_of_pieces_per_real_item"));
+            game_hash.remove(&Yaml::from_str("enable_forced_local_filler_items"));
 
-            if let Some(kong_checks) = game_hash.remove(&Yaml::from_str("kong_checks")) {
-                game_hash.insert(Yaml::from_str("kong_locations"), kong_checks);
+            let grid_type_yaml = game_hash.remove(&Yaml::from_str("grid_type"));
+            let rotatoins_yaml = game_hash.remove(&Yaml::from_str("rotations"));
+            if grid_type_yaml.is_none() && rotatoins_yaml.is_none() {
+                return;
             }
 
-            if let Some(dk_coin_checks) = game_hash.remove(&Yaml::from_str("dk_coin_checks")) {
-                game_hash.insert(Yaml::from_str("dk_coin_locations"), dk_coin_checks);
+            let grid_type = grid_type_yaml.as_ref().and_then(Yaml::as_str).unwrap_or("square");
+            let rotations = rotatoins_yaml.as_ref().and_then(Yaml::as_str).unwrap_or("no_rotation");
+
+            game_hash.insert(
+                Yaml::from_str("grid_type_and_rotations"),
+                Yaml::from_str(match (grid_type, rotations) {
+                    ("square", "no_rotation") => "square_no_rotation",
+                    ("square", "per_90_degrees") => "square_90_rotation",
+                    ("square", "per_180_degrees") => "square_180_rotation",
+                    ("hexagonal", "no_rotation") => "hex_no_rotation",
+                    ("hexagonal", "per_90_degrees") => "hex_60_rotation",
+                    ("hexagonal", "per_180_degrees") => "hex_180_rotation",
+                    ("meme_one_row", "no_rotation") => "meme_one_row_no_rotation",
+                    ("meme_one_row", "per_180_degrees") => "meme_one_row_180_rotation",
+                    ("meme_one_column", "no_rotation") => "meme_one_column_no_rotation",
+                    ("meme_one_column", "per_180_degrees") => "meme_one_column_180_rotation",
+                    (_, _) => "square_no_rotations",
+                }),
+            );
+        }
+        Some("Psychonauts") if game_hash.contains_key(&Yaml::from_str("LootboxVaults")) => {
+            game_hash.remove(&Yaml::from_str("LootboxVaults"));
+            game_hash.insert(Yaml::from_str("VaultCount"), Yaml::Integer(101));
+        }
+        Some("Factorio") => {
+            if let Some(world_gen) = game_hash.get_mut(&Yaml::from_str("world_gen")).and_then(|yaml| yaml.as_mut_hash()) {
+                world_gen.remove(&Yaml::from_str("terrain_segmentation"));
+            }
+        }
+        Some("Paper Mario The Thousand Year Door") => {
+            if let Some(chapter_clears) = game_hash.remove(&Yaml::from_str("chapter_clears")) {
+                game_hash.insert(Yaml::from_str("goal_stars"), chapter_clears.clone());
+                game_hash.insert(Yaml::from_str("palace_stars"), chapter_clears);
+                game_hash.insert(Yaml::from_str("goal"), Yaml::from_str("crystal_stars"));
+            }
+        }
+        Some("The Minish Cap") => {
+            if option_can_be(game_hash, "goal_vaati", &Yaml::Boolean(true), &Yaml::Boolean(false)) {
+                game_hash.insert(Yaml::from_str("goal"), Yaml::from_str("pedestal"));
+            } else {
+                game_hash.remove(&Yaml::from_str("goal_vaati"));
+            }
+        }
+        Some("Stacklands") => {
+            if option_can_be(game_hash, "goal", &Yaml::from_str(""), &Yaml::from_str("kill_wicked_witch")) {
+                game_hash.insert(Yaml::from_str("boards"), Yaml::from_str("mainland_and_forest"));
+            } else if option_can_be(game_hash, "goal", &Yaml::from_str(""), &Yaml::from_str("kill_demon")) {
+                game_hash.insert(Yaml::from_str("boards"), Yaml::from_str("mainland_only"));
+            }
+        }
+        Some("Metroid: Zero Mission") => {
+            game_hash.remove(&Yaml::from_str("unknown_items_always_usable"));
+        }
+        Some("Trackmania") => {
+            if let Some(disable_bronze) = game_hash.remove(&Yaml::from_str("disable_bronze")) {
+                game_hash.insert(Yaml::from_str("disable_bronze_locations"), disable_bronze.clone());
+                game_hash.insert(Yaml::from_str("disable_bronze_medals"), disable_bronze);
+            }
+
+            if let Some(disable_silver) = game_hash.remove(&Yaml::from_str("disable_silver")) {
+                game_hash.insert(Yaml::from_str("disable_silver_locations"), disable_silver.clone());
+                game_hash.insert(Yaml::from_str("disable_silver_medals"), disable_silver);
+            }
+
+            if let Some(disable_gold) = game_hash.remove(&Yaml::from_str("disable_gold")) {
+                game_hash.insert(Yaml::from_str("disable_gold_locations"), disable_gold.clone());
+                game_hash.insert(Yaml::from_str("disable_gold_medals"), disable_gold);
+            }
+        }
+        Some("The Legend of Zelda - Phantom Hourglass") => {
+            game_hash.remove(&Yaml::from_str("dungeon_hints"));
+
+            if option_can_be(game_hash, "shuffle_island_entrances", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
+                game_hash.insert(Yaml::from_str("shuffle_ports"), Yaml::from_str("shuffle"));
+                game_hash.insert(Yaml::from_str("shuffle_caves"), Yaml::from_str("shuffle"));
+                game_hash.insert(Yaml::from_str("shuffle_houses"), Yaml::from_str("shuffle"));
+                game_hash.remove(&Yaml::from_str("shuffle_island_entrances"));
+            }
+        }
+        // IGNORE
+        Some("Bloons TD6") => {
+            if let Some(trap_weights) = game_hash.get_mut(&Yaml::from_str("trap_weights")).and_then(|trap_weights| trap_weights.as_mut_hash()) {
+                for trap in [
+                    "144p Trap",
+                    "Chaos Control Trap",
+                    "Flood Trap",
+                    "Input Sequence Trap",
+                    "Math Quiz Trap",
+                    "Number Sequence Trap",
+                    "Pokemon Trivia Trap",
+                    "Screen Flip Trap",
+                    "Shuffle Trap",
+                    "Swap Trap",
+                    "Trivia Trap",
+                    "Yap Trap",
+                    "Zoom Trap",
+                ] {
+                    let key = Yaml::from_str(trap);
+                    if !trap_weights.contains_key(&key) {
+                        trap_weights.insert(key, Yaml::Integer(0));
+                    }
+                }
+            }
+        }
+        Some("Yu-Gi-Oh! 2006") => {
+            game_hash.remove(&Yaml::from_str("starter_deck"));
+            game_hash.remove(&Yaml::from_str("normalize_booster_pack_prices"));
+            game_hash.remove(&Yaml::from_str("normalize_booster_pack_rarities"));
+            game_hash.remove(&Yaml::from_str("randomize_pack_contents"));
+            game_hash.remove(&Yaml::from_str("custom_structure_deck"));
+            game_hash.remove(&Yaml::from_str("custom_starter_deck"));
+        }
+        Some("Rabi-Ribi") => {
+            game_hash.remove(&Yaml::from_str("open_mode"));
+        }
+        Some("Spyro 3") => {
+            game_hash.remove(&Yaml::from_str("logic_haunted_agent_9_early"));
+            if let Some(easy_skateboarding) = game_hash.remove(&Yaml::from_str("easy_skateboarding")) {
+                game_hash.insert(Yaml::from_str("easy_skateboarding_lizards"), easy_skateboarding.clone());
+                game_hash.insert(Yaml::from_str("easy_skateboarding_points"), easy_skateboarding.clone());
+                game_hash.insert(Yaml::from_str("easy_skateboarding_lost_fleet"), easy_skateboarding.clone());
+                game_hash.insert(Yaml::from_str("easy_skateboarding_super_bonus_round"), easy_skateboarding);
             }
+        }
+        Some("PokePark") => {
+            game_hash.remove(&Yaml::from_str("starting zone"));
+        }
+        Some("The Binding of Isaac Repentance") => {
+            if let Some(mut total_locations) = game_hash.remove(&Yaml::from_str("total_locations")) {
+                match &mut total_locations {
+                    Yaml::Integer(value) => {
+                        *value += 1;
+                        *value /= 2
+                    }
+                    Yaml::Hash(option_hash) => {
+                        let mut to_map = vec![];
+
+                        for old in option_hash.keys() {
+                            match old {
+                                Yaml::Integer(value) => to_map.push(*value),
+                                Yaml::String(value) => {
+                                    if let Ok(value) = value.parse() {
+                                        to_map.push(value);
+                                    }
+                                }
+                                _ => (),
+                            }
+                        }
+
+                        for old in to_map {
+                            if let Some(val) = option_hash.remove(&Yaml::Integer(old)) {
+                                option_hash.insert(Yaml::Integer((old + 1) / 2), val);
+                            }
 
-            if let Some(balloon_checks) = game_hash.remove(&Yaml::from_str("balloon_checks")) {
-                game_hash.insert(Yaml::from_str("balloon_locations"), balloon_checks);
-            }
+                            if let Some(val) = option_hash.remove(&Yaml::String(old.to_string())) {
+                                option_hash.insert(Yaml::Integer((old + 1) / 2), val);
+                            }
+                        }
+                    }
+                    _ => (),
+                }
 
-            if let Some(banana_checks) = game_hash.remove(&Yaml::from_str("banana_checks")) {
-                game_hash.insert(Yaml::from_str("banana_locations"), banana_checks);
+                game_hash.insert(Yaml::from_str("additional_item_locations"), total_locations);
             }
 
-            if let Some(coin_checks) = game_hash.remove(&Yaml::from_str("coin_checks")) {
-                game_hash.insert(Yaml::from_str("coin_locations"), coin_checks);
+            if let Some(item_pickup_step) = game_hash.remove(&Yaml::from_str("item_pickup_step")) {
+                game_hash.insert(Yaml::from_str("item_location_step"), item_pickup_step);
             }
 
-            if let Some(bird_checks) = game_hash.remove(&Yaml::from_str("bird_checks")) {
-                game_hash.insert(Yaml::from_str("bird_locations"), bird_checks);
-            }
-        }
-        Some("APBingo") => {
-            if let Some(board_size) = game_hash.get_mut(&Yaml::from_str("board_size")) {
-                move_option_weight(board_size, "3", "4");
-            }
-        }
-        Some("Aquaria") => rename_true_false(game_hash, "light_needed_to_get_to_dark_places", "on", "off"),
-        Some("Pokemon Stadium") => {
-            if let Some(victory_condition) = game_hash.get_mut(&Yaml::from_str("VictoryCondition")) {
-                move_option_weight(victory_condition, "clear_master_ball_cup", "clear_master_ball_cups");
-            }
+            game_hash.remove(&Yaml::from_str("required_locations"));
+            game_hash.remove(&Yaml::from_str("force_lategame"));
+            game_hash.remove(&Yaml::from_str("win_collects_missed_locations"));
+            game_hash.remove(&Yaml::from_str("additional_item_locations"));
+            game_hash.remove(&Yaml::from_str("item_location_step"));
         }
-        Some("shapez") => {
-            if let Some(goal_amount) = game_hash.get_mut(&Yaml::from_str("goal_amount")) {
-                move_option_weight(goal_amount, "random", "random-range-1-100");
-                move_option_weight_matches(
-                    goal_amount,
-                    |yaml| yaml.as_i64().is_some_and(|v| v > 100) || yaml.as_str().is_some_and(|str| str.parse::<i64>().is_ok_and(|v| v > 100)),
-                    "100",
-                );
-            }
+        Some("Lunacid") => {
+            game_hash.remove(&Yaml::from_str("experience"));
+            game_hash.remove(&Yaml::from_str("weapon_experience"));
         }
-        Some("Baba Is You") => push_value_or_default(&mut notes, game_hash, "logic_difficulty", "normal"),
-        Some("Spyro 2") => {
-            resolve_weighted_option(game_hash, "open_world_ability_and_warp_unlocks");
-            if option_can_be(game_hash, "open_world_ability_and_warp_unlocks", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
-                game_hash.insert(Yaml::from_str("start_with_abilities"), Yaml::Boolean(true));
-            }
-
-            if let Some(open_world_ability_and_warp_unlocks) = game_hash.get_mut(&Yaml::from_str("open_world_ability_and_warp_unlocks")) {
-                move_option_weight(open_world_ability_and_warp_unlocks, "vanilla", "generic");
-            }
+        Some("Kirby Super Star") => {
+            if let Some(the_great_cave_offensive_gold_thresholds) = game_hash.get_mut(&Yaml::from_str("the_great_cave_offensive_gold_thresholds")) {
+                match the_great_cave_offensive_gold_thresholds {
+                    Yaml::Real(val) | Yaml::String(val) => {
+                        if let Ok(parsed) = val.parse::<f64>()
+                            && parsed < 1.0
+                        {
+                            *val = (parsed * 100.0).to_string();
+                        }
+                    }
+                    Yaml::Hash(linked_hash_map) => {
+                        let mut new_hash = LinkedHashMap::new();
 
-            push_value_or_default(&mut notes, game_hash, "trick_difficulty", "off");
-            push_value_or_default(&mut notes, game_hash, "custom_tricks", "[]");
-        }
-        Some("Slime Rancher") => {
-            let skips: Vec<_> = ["easy_skips", "precise_movement", "dangerous_skips", "obscure_locations", "largo_jumps", "jetpack_boosts"]
-                .iter()
-                .filter(|option| option_can_be(game_hash, option, &Yaml::Boolean(false), &Yaml::Boolean(true)))
-                .copied()
-                .collect();
+                        for (key, val) in linked_hash_map.iter() {
+                            if let Yaml::Real(val) | Yaml::String(val) = key
+                                && let Ok(parsed) = val.parse::<f64>()
+                                && parsed < 1.0
+                            {
+                                new_hash.insert(key.to_owned(), Yaml::Real((parsed * 100.0).to_string()));
+                            } else {
+                                new_hash.insert(key.to_owned(), val.to_owned());
+                            }
+                        }
 
-            if skips.is_empty() {
-                notes.push(String::from("Skips: none"));
-            } else {
-                notes.push(format!("Skips: [{}]", skips.join(", ")));
+                        *linked_hash_map = new_hash;
+                    }
+                    _ => (),
+                }
             }
 
-            push_value_or_default(&mut notes, game_hash, "enable_stylish_dlc_treasure_pods", "false");
-        }
-        // IGNORE
-        Some("Slime Rancher 2") => {
-            if let Some(goal) = game_hash.get_mut(&Yaml::from_str("goal")) {
-                move_option_weight(goal, "prismacore_enter", "prismacore");
-                move_option_weight(goal, "prismacore_stabilize", "prismacore");
+            if let Some(kirby_flavor) = game_hash.remove(&Yaml::from_str("kirby_flavor")) {
+                let mut flavor_hash = LinkedHashMap::new();
+                flavor_hash.insert(Yaml::from_str("default_kirby"), kirby_flavor);
+                game_hash.insert(Yaml::from_str("kirby_flavors"), Yaml::Hash(flavor_hash));
             }
         }
-        Some("Hammerwatch") => {
-            rename_true_false(game_hash, "randomize_enemy_loot", "on", "off");
-            if let Some(key_mode) = game_hash.get_mut(&Yaml::from_str("key_mode")) {
-                move_option_weight(key_mode, "vanilla", "generic");
-            }
+        Some("Super Mario Sunshine") => {
+            game_hash.remove(&Yaml::from_str("yoshi_mode"));
         }
-        Some("Pizza Tower") => {
-            game_hash.remove(&Yaml::from_str("jumpscare"));
+        Some("Yu-Gi-Oh! Dungeon Dice Monsters") => {
+            game_hash.remove(&Yaml::from_str("duelist_rematches"));
         }
-        Some("Lego Star Wars: The Complete Saga") => {
-            if let Some(chapter_unlock_requirement) = game_hash.get_mut(&Yaml::from_str("chapter_unlock_requirement")) {
-                move_option_weight(chapter_unlock_requirement, "story_characters", "vanilla_characters");
+        Some("Ship of Harkinian") => {
+            game_hash.remove(&Yaml::from_str("skip_ganons_trials"));
+
+            if option_can_be(game_hash, "key_rings", &Yaml::Boolean(false), &Yaml::Boolean(true)) {
+                game_hash.insert(Yaml::from_str("key_rings_count"), Yaml::Integer(9));
             }
         }
-        Some("Reventure") => {
-            push_value_or_default(&mut notes, game_hash, "hardjumps", "false");
-            push_value_or_default(&mut notes, game_hash, "logic", "{}");
-        }
-        Some("CorruObserver") => push_value_or_default(&mut notes, game_hash, "mods", "[]"),
-        Some("Little Witch Nobeta") => {
-            push_value_or_default(&mut notes, game_hash, "wind_requirements", "start_without");
-            push_value_or_default(&mut notes, game_hash, "skips_in_logic", "[]");
-        }
-        Some("CrossCode") => push_value_or_default(&mut notes, game_hash, "enable_dlc", "false"),
-        // IGNORE
-        Some("Backlog Expedition") => {
-            if let Some(beaten_to_goal) = game_hash.remove(&Yaml::from_str("beaten_to_goal")) {
-                game_hash.insert(Yaml::from_str("treasures_to_goal"), beaten_to_goal);
-            }
+        Some("Pizza Tower") => {
+            game_hash.remove(&Yaml::from_str("jumpscare"));
         }
-        Some("Another Crabs Treasure") => push_value_or_default(&mut notes, game_hash, "logic_rules", "vanilla"),
         Some("Super Smash Bros. Melee") => {
             let mut goal_triggers = vec![];
 
@@ -1906,81 +2084,19 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 game_hash.insert(Yaml::from_str("goal_triggers"), Yaml::Array(goal_triggers));
             }
         }
-        Some("Keep Talking and Nobody Explodes") => {
-            push_value_or_default(&mut notes, game_hash, "adventure_mode", "vanilla_vanguard");
-        }
         Some("La-Mulana 2") => {
             game_hash.remove(&Yaml::from_str("potsanity"));
-            game_hash.insert(Yaml::from_str("potsanity_low_value"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_high_value"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_shuriken"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_rolling_shuriken"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_earth_spear"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_flare"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_caltrops"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_chakram"), Yaml::Boolean(true));
-            game_hash.insert(Yaml::from_str("potsanity_bomb"), Yaml::Boolean(true));
-
-            push_value_or_default(&mut notes, game_hash, "oannesanity", "false");
-            push_value_or_default(&mut notes, game_hash, "logic_difficulty", "normal");
-            push_value_or_default(&mut notes, game_hash, "costume_clip", "false");
-            push_value_or_default(&mut notes, game_hash, "dlc_item_logic", "false");
-        }
-        Some("UNBEATABLE Arcade") => {
-            push_value_or_default(&mut notes, game_hash, "use_dlc", "[]");
-        }
-        Some("Yellow Taxi Goes Vroom") => {
-            push_value_or_default(&mut notes, game_hash, "include_out_of_bounds", "none");
-        }
-        Some("The Grinch") => {
-            push_value_or_default(&mut notes, game_hash, "advanced_logic", "false");
         }
         Some("ULTRAKILL") => {
-            rename_true_false(game_hash, "randomize_secondary_fire", "split", "disabled");
             game_hash.remove(&Yaml::from_str("goal"));
             game_hash.remove(&Yaml::from_str("include_secret_mission_completion"));
             game_hash.remove(&Yaml::from_str("boss_rewards"));
             game_hash.remove(&Yaml::from_str("starting_weapon"));
-            push_value_or_default(&mut notes, game_hash, "speedrunner_logic", "false");
-        }
-        // IGNORE
-        Some("Dark Souls Remastered") => {
-            push_value_or_default(&mut notes, game_hash, "logic_to_access_catacombs", "ornstein_and_smough");
-            push_value_or_default(&mut notes, game_hash, "logic_to_access_totg", "skull_lantern");
-        }
-        Some("Elementipelago") => {
-            let key = Yaml::from_str("start_inventory");
-            if let Some(start_inventory) = game_hash.get_mut(&key) {
-                if let Some(hash) = start_inventory.as_mut_hash() {
-                    hash.insert(Yaml::from_str("Filters"), Yaml::Integer(1));
-                }
-            } else {
-                let mut hash = LinkedHashMap::new();
-                hash.insert(Yaml::from_str("Filters"), Yaml::Integer(1));
-                game_hash.insert(key, Yaml::Hash(hash));
-            }
         }
         Some("Mega Man X2") => {
-            if let Some(pickupsanity) = game_hash.remove(&Yaml::from_str("pickupsanity")) {
-                game_hash.insert(Yaml::from_str("pickup_locations"), pickupsanity);
-            }
-            if let Some(starting_life_count) = game_hash.remove(&Yaml::from_str("starting_life_count")) {
-                game_hash.insert(Yaml::from_str("starting_hp"), starting_life_count);
-            }
             game_hash.remove(&Yaml::from_str("quick_charge_in_pool"));
             game_hash.remove(&Yaml::from_str("speedster_in_pool"));
             game_hash.remove(&Yaml::from_str("super_recover_in_pool"));
-            if let Some(boss_weakness_rando) = game_hash.get_mut(&Yaml::from_str("boss_weakness_rando")) {
-                move_option_weight(boss_weakness_rando, "shuffled", "simple");
-            }
-            if let Some(x_hunters_medal_count) = game_hash.remove(&Yaml::from_str("x_hunters_medal_count")) {
-                game_hash.insert(Yaml::from_str("x_hunter_base_medal_count"), x_hunters_medal_count);
-            }
-            if let Some(mut base_open) = game_hash.remove(&Yaml::from_str("base_open")) {
-                move_option_weight(&mut base_open, "false", "item");
-                move_option_weight(&mut base_open, "true", "medals");
-                game_hash.insert(Yaml::from_str("x_hunter_base_open"), base_open);
-            }
         }
         Some("Crypt of the NecroDancer") => {
             if let Some(trap_weights) = game_hash.get_mut(&Yaml::from_str("trap_weights")).and_then(|trap_weights| trap_weights.as_mut_hash()) {
@@ -2022,34 +2138,85 @@ pub fn handle_special(doc: &mut Yaml, game: &Yaml, name: &str) -> Vec<String> {
                 trap_weights.remove(&Yaml::from_str("Zoom Out Trap"));
             }
         }
-        Some("Skyward Sword") => {
-            if let Some(randomize_entrances) = game_hash.get_mut(&Yaml::from_str("randomize_entrances")) {
-                move_option_weight(randomize_entrances, "required_dungeons_separately", "required_dungeons_only");
-                move_option_weight(randomize_entrances, "all_surface_dungeons", "dungeons_only");
+        _ => (),
+    };
+}
+
+// renames the options that triggers look at or change for this game, so they keep referring to options that exist
+fn migrate_triggers(doc: &mut Yaml, game: &Yaml, warnings: &mut Warnings) {
+    let triggers_key = Yaml::from_str("triggers");
+    let Some(hash) = doc.as_mut_hash() else {
+        return;
+    };
+
+    if let Some(triggers) = hash.get_mut(&triggers_key) {
+        migrate_trigger_list(triggers, game, warnings);
+    }
+
+    if let Some(triggers) = hash.get_mut(game).and_then(Yaml::as_mut_hash).and_then(|game_hash| game_hash.get_mut(&triggers_key)) {
+        migrate_trigger_list(triggers, game, warnings);
+    }
+}
+
+fn migrate_trigger_list(triggers: &mut Yaml, game: &Yaml, warnings: &mut Warnings) {
+    let category_key = Yaml::from_str("option_category");
+    let name_key = Yaml::from_str("option_name");
+    let result_key = Yaml::from_str("option_result");
+    let options_key = Yaml::from_str("options");
+
+    for trigger in triggers.as_mut_vec().into_iter().flatten() {
+        let Some(trigger_hash) = trigger.as_mut_hash() else {
+            continue;
+        };
+
+        if trigger_hash.get(&category_key) == Some(game)
+            && let (Some(name), Some(result)) = (trigger_hash.get(&name_key).cloned(), trigger_hash.get(&result_key).cloned())
+        {
+            let mut condition = LinkedHashMap::new();
+            condition.insert(name.clone(), result);
+
+            if !migrate_trigger_options(&mut condition, game) {
+                warnings.push(
+                    WarningKind::Triggers,
+                    game.as_str(),
+                    format!("contains a trigger on {} that cannot be migrated to the current options", to_string(&name)),
+                );
+            } else if let Some((name, result)) = condition.pop_front() {
+                trigger_hash.replace(name_key.clone(), name);
+                trigger_hash.replace(result_key.clone(), result);
             }
         }
-        Some("Haste") => {
-            if let Some(shopsanity_seperate) = game_hash.remove(&Yaml::from_str("shopsanity_seperate")) {
-                game_hash.insert(Yaml::from_str("shopsanity_separate"), shopsanity_seperate);
-            }
-            if let Some(shopsanity_seperate_rate) = game_hash.remove(&Yaml::from_str("shopsanity_seperate_rate")) {
-                game_hash.insert(Yaml::from_str("shopsanity_separate_rate"), shopsanity_seperate_rate);
-            }
-            if let Some(permanent_items) = game_hash.remove(&Yaml::from_str("permanent_items")) {
-                game_hash.insert(Yaml::from_str("persistent_items"), permanent_items);
-            }
-            if let Some(permanent_item_quantities) = game_hash.remove(&Yaml::from_str("permanent_item_quantities")) {
-                game_hash.insert(Yaml::from_str("persistent_item_quantities"), permanent_item_quantities);
-            }
+
+        if let Some(options) = trigger_hash.get_mut(&options_key).and_then(Yaml::as_mut_hash)
+            && let Some(game_options) = options.get_mut(game).and_then(Yaml::as_mut_hash)
+            && !migrate_trigger_options(game_options, game)
+        {
+            let names: Vec<_> = game_options.keys().map(to_string).collect();
+            warnings.push(
+                WarningKind::Triggers,
+                game.as_str(),
+                format!("contains a trigger changing {} that cannot be migrated to the current options", names.join(", ")),
+            );
         }
-        _ => (),
-    };
+    }
+}
 
-    notes
+// only renames are applied to triggers; options that were split up, merged or removed are left as they are, as migrating them needs the rest of the yaml
+fn migrate_trigger_options(options: &mut LinkedHashMap<Yaml, Yaml>, game: &Yaml) -> bool {
+    let mut derived = options.clone();
+    derive_game_options(&mut derived, game);
+    if derived != *options {
+        return false;
+    }
+
+    rename_game_options(options, game);
+    true
 }
 
-fn push_value_or_default(notes: &mut Vec<String>, hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &str) {
-    notes.push(format!("{key}: {}", get_value_or_default(hash, key, default)));
+fn push_value_or_default(notes: &mut Vec<Note>, hash: &LinkedHashMap<Yaml, Yaml>, triggers: &[Trigger], key: &str, default: &str) {
+    let mut note = Note::new(key, get_value_or_default(hash, key, default));
+    note.trigger_changes = trigger_changes(triggers, key);
+    notes.push(note);
 }
 
 fn get_value_or_default(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &str) -> String {
@@ -2060,7 +2227,7 @@ fn get_value_or_default(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &s
     }
 }
 
-fn to_string(yaml: &Yaml) -> String {
+pub(crate) fn to_string(yaml: &Yaml) -> String {
     match yaml {
         Yaml::Real(str) | Yaml::String(str) => str.to_owned(),
         Yaml::Integer(int) => int.to_string(),
@@ -2095,7 +2262,7 @@ fn to_string(yaml: &Yaml) -> String {
     }
 }
 
-fn option_can_be(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &Yaml, cmp: &Yaml) -> bool {
+pub(crate) fn option_can_be(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &Yaml, cmp: &Yaml) -> bool {
     if let Some(value) = hash.get(&Yaml::from_str(key)).cloned().map(handle_non_string_strings) {
         if value == *cmp {
             true
@@ -2110,6 +2277,19 @@ fn option_can_be(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &Yaml, cm
     }
 }
 
+pub(crate) fn option_can_be_at_least(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, minimum: i64) -> bool {
+    if let Some(value) = hash.get(&Yaml::from_str(key)) {
+        if let Some(hash) = value.as_hash() {
+            hash.iter()
+                .any(|(value, weight)| as_i64(value).is_some_and(|value| value >= minimum) && as_i64(weight).is_some_and(|weight| weight > 0))
+        } else {
+            as_i64(value).is_some_and(|value| value >= minimum)
+        }
+    } else {
+        false
+    }
+}
+
 fn option_can_be_other_than(hash: &LinkedHashMap<Yaml, Yaml>, key: &str, default: &Yaml, cmp: &Yaml) -> bool {
     if let Some(value) = hash.get(&Yaml::from_str(key)).cloned().map(handle_non_string_strings) {
         if let Some(hash) = value.as_hash() {
@@ -2208,3 +2388,146 @@ fn move_option_weight_to_yaml(value: &mut Yaml, from_str: &str, to: Yaml) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust2::YamlLoader;
+
+    use super::*;
+
+    fn migrate(game: &str, yaml: &str) -> (Yaml, Vec<String>) {
+        let mut doc = YamlLoader::load_from_str(yaml).unwrap().remove(0);
+        let mut warnings = Warnings::new("Alice", None);
+        migrate_triggers(&mut doc, &Yaml::from_str(game), &mut warnings);
+
+        (doc, warnings.into_vec().into_iter().map(|warning| warning.message).collect())
+    }
+
+    fn expected(yaml: &str) -> Yaml {
+        YamlLoader::load_from_str(yaml).unwrap().remove(0)
+    }
+
+    #[test]
+    fn trigger_options_are_renamed() {
+        let (doc, warnings) = migrate(
+            "Portal 2",
+            "
+triggers:
+  - option_category: Portal 2
+    option_name: open_world
+    option_result: true
+    options:
+      Portal 2:
+        cutscenelevels: true
+",
+        );
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            doc,
+            expected(
+                "
+triggers:
+  - option_category: Portal 2
+    option_name: game_mode
+    option_result: open_world
+    options:
+      Portal 2:
+        cutscene_levels: true
+"
+            )
+        );
+    }
+
+    #[test]
+    fn trigger_values_are_renamed() {
+        let (doc, warnings) = migrate(
+            "Stardew Valley",
+            "
+Stardew Valley:
+  triggers:
+    - option_category: Stardew Valley
+      option_name: trap_items
+      option_result: hard
+      options:
+        Stardew Valley:
+          entrance_randomization: chaos
+",
+        );
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            doc,
+            expected(
+                "
+Stardew Valley:
+  triggers:
+    - option_category: Stardew Valley
+      option_name: trap_difficulty
+      option_result: hard
+      options:
+        Stardew Valley:
+          entrance_randomization: disabled
+"
+            )
+        );
+    }
+
+    #[test]
+    fn merged_options_are_not_migrated() {
+        let yaml = "
+Jigsaw:
+  grid_type: hexagonal
+  triggers:
+    - option_category: Jigsaw
+      option_name: grid_type
+      option_result: hexagonal
+      options:
+        Jigsaw:
+          rotations: per_180_degrees
+";
+        let (doc, warnings) = migrate("Jigsaw", yaml);
+
+        assert_eq!(doc, expected(yaml));
+        assert_eq!(
+            warnings,
+            [
+                "contains a trigger on grid_type that cannot be migrated to the current options",
+                "contains a trigger changing rotations that cannot be migrated to the current options"
+            ]
+        );
+    }
+
+    #[test]
+    fn derived_options_are_not_migrated() {
+        for (game, option) in [
+            ("Against the Storm", "enable_dlc: true"),
+            ("The Minish Cap", "goal_vaati: false"),
+            ("Paper Mario The Thousand Year Door", "chapter_clears: 5"),
+        ] {
+            let (name, result) = option.split_once(": ").unwrap();
+            let yaml = format!(
+                "
+triggers:
+  - option_category: {game}
+    option_name: {name}
+    option_result: {result}
+    options:
+      {game}:
+        {option}
+"
+            );
+            let (doc, warnings) = migrate(game, &yaml);
+
+            assert_eq!(doc, expected(&yaml), "{game}");
+            assert_eq!(
+                warnings,
+                [
+                    format!("contains a trigger on {name} that cannot be migrated to the current options"),
+                    format!("contains a trigger changing {name} that cannot be migrated to the current options")
+                ],
+                "{game}"
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::{fmt::Write as FmtWrite, fs::File, io::Write, path::Path};
+
+use crate::{
+    warning::{Warning, WarningKind},
+    write::Note,
+};
+
+struct SlotSummary {
+    name: String,
+    bucket_id: Option<String>,
+    games: Vec<(String, u32)>,
+    points: u32,
+}
+
+// what hosts review after a run, collected while the slots are processed
+#[derive(Default)]
+pub struct Summary {
+    slots: Vec<SlotSummary>,
+    rejected: Vec<String>,
+}
+
+impl Summary {
+    pub fn add(&mut self, name: &str, bucket_id: Option<&str>, games: &[(String, u32, Vec<Note>)], points: u32) {
+        self.slots.push(SlotSummary {
+            name: name.to_string(),
+            bucket_id: bucket_id.map(String::from),
+            games: games.iter().map(|(game, count, _)| (game.clone(), *count)).collect(),
+            points,
+        });
+    }
+
+    pub fn add_rejected(&mut self, name: &str, bucket_id: Option<&str>) {
+        self.rejected.push(describe_slot(name, bucket_id));
+    }
+
+    pub fn to_markdown(&self, warnings: &[Warning]) -> String {
+        let mut out = String::new();
+        let total_points: u32 = self.slots.iter().map(|slot| slot.points).sum();
+
+        let _ = writeln!(out, "# Batch summary\n");
+        let _ = writeln!(out, "{} slot(s), {total_points} point(s) in total, {} rejected slot(s)", self.slots.len(), self.rejected.len());
+
+        // slots are counted once per game, even if the game is listed more than once, copies count every document of it
+        let mut games: Vec<(&str, u32, u32)> = vec![];
+        for slot in &self.slots {
+            for (i, (game, count)) in slot.games.iter().enumerate() {
+                let first_entry = !slot.games[..i].iter().any(|(earlier, _)| earlier == game);
+                if let Some((_, slots, copies)) = games.iter_mut().find(|(existing, _, _)| existing == game) {
+                    *slots += u32::from(first_entry);
+                    *copies += count;
+                } else {
+                    games.push((game, 1, *count));
+                }
+            }
+        }
+        games.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(b.0)));
+
+        let _ = writeln!(out, "\n## Games\n");
+        let _ = writeln!(out, "| Game | Slots | Copies |");
+        let _ = writeln!(out, "| --- | ---: | ---: |");
+        for (game, slots, copies) in games {
+            let _ = writeln!(out, "| {} | {slots} | {copies} |", game.replace('|', "\\|"));
+        }
+
+        let with_kind = |kinds: &[WarningKind]| -> Vec<String> {
+            warnings
+                .iter()
+                .filter(|warning| kinds.contains(&warning.kind))
+                .map(|warning| {
+                    let slot = describe_slot(&warning.slot, warning.bucket_id.as_deref());
+                    match &warning.game {
+                        Some(game) => format!("{slot}: {game} {}", warning.message),
+                        None => format!("{slot}: {}", warning.message),
+                    }
+                })
+                .collect()
+        };
+        // for kinds that can come up many times per slot, the details are in the warnings
+        let count_kind = |kinds: &[WarningKind]| -> Vec<String> {
+            let mut slots: Vec<(String, u32, Vec<&str>)> = vec![];
+            for warning in warnings.iter().filter(|warning| kinds.contains(&warning.kind)) {
+                let slot = describe_slot(&warning.slot, warning.bucket_id.as_deref());
+                let index = slots.iter().position(|(existing, _, _)| *existing == slot).unwrap_or_else(|| {
+                    slots.push((slot, 0, vec![]));
+                    slots.len() - 1
+                });
+
+                let (_, count, games) = &mut slots[index];
+                *count += 1;
+                if let Some(game) = warning.game.as_deref()
+                    && !games.contains(&game)
+                {
+                    games.push(game);
+                }
+            }
+
+            slots
+                .into_iter()
+                .map(|(slot, count, games)| {
+                    if games.is_empty() {
+                        format!("{slot}: {count} warning(s)")
+                    } else {
+                        format!("{slot}: {count} warning(s) for {}", games.join(", "))
+                    }
+                })
+                .collect()
+        };
+        let with_games = |filter: &dyn Fn(&SlotSummary) -> Option<String>| -> Vec<String> {
+            self.slots
+                .iter()
+                .filter_map(|slot| filter(slot).map(|detail| format!("{}: {detail}", describe_slot(&slot.name, slot.bucket_id.as_deref()))))
+                .collect()
+        };
+
+        let sections = [
+            ("Rejected slots", self.rejected.clone()),
+            ("Invalid games", with_kind(&[WarningKind::NoGame, WarningKind::InvalidGame, WarningKind::GameWeights])),
+            ("Banned games", with_kind(&[WarningKind::BannedGame])),
+            ("Triggers", count_kind(&[WarningKind::Triggers])),
+            ("Named-world plandos", count_kind(&[WarningKind::NamedWorldPlando, WarningKind::UnresolvedWorld])),
+            (
+                "Keymaster's Keeps",
+                with_games(&|slot| {
+                    let count: u32 = slot.games.iter().filter(|(game, _)| game == "Keymaster's Keep").map(|(_, count)| count).sum();
+                    (count > 0).then(|| format!("{count} Keymaster's Keep(s)"))
+                }),
+            ),
+            ("More than 8 games", with_kind(&[WarningKind::TooManyGames])),
+        ];
+
+        for (title, entries) in sections {
+            let _ = writeln!(out, "\n## {title}\n");
+            if entries.is_empty() {
+                let _ = writeln!(out, "None");
+            }
+            for entry in entries {
+                let _ = writeln!(out, "- {entry}");
+            }
+        }
+
+        out
+    }
+}
+
+fn describe_slot(name: &str, bucket_id: Option<&str>) -> String {
+    match bucket_id {
+        Some(bucket_id) => format!("{name} (bucket {bucket_id})"),
+        None => name.to_string(),
+    }
+}
+
+pub fn write_summary(path: &Path, summary: &str) {
+    if let Err(err) = File::create(path).and_then(|mut writer| writer.write_all(summary.as_bytes())) {
+        println!("Failed to write to summary file: {err}");
+    }
+}
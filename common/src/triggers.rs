@@ -0,0 +1,196 @@
+use yaml_rust2::Yaml;
+
+use crate::{
+    special::{option_can_be, to_string},
+    warning::{WarningKind, Warnings},
+};
+
+// a single trigger, the category is None for options at the root of the document
+#[derive(Debug, Clone)]
+pub struct Trigger {
+    pub category: Option<String>,
+    pub option: String,
+    pub result: Yaml,
+    pub changes: Vec<TriggerChange>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TriggerChange {
+    pub category: Option<String>,
+    pub option: String,
+    pub value: Yaml,
+}
+
+// reads the triggers at the root of the document and in the game's options, which Archipelago applies in that order
+pub fn parse_triggers(doc: &Yaml, game: &Yaml, warnings: &mut Warnings) -> Vec<Trigger> {
+    let triggers_key = Yaml::from_str("triggers");
+    let Some(hash) = doc.as_hash() else {
+        return vec![];
+    };
+
+    let root = hash.get(&triggers_key);
+    let game_level = hash.get(game).and_then(Yaml::as_hash).and_then(|game_hash| game_hash.get(&triggers_key));
+
+    let mut triggers = vec![];
+    for block in [root, game_level].into_iter().flatten() {
+        let Some(block) = block.as_vec() else {
+            warnings.push(WarningKind::Triggers, game.as_str(), "contains triggers that are not a list");
+            continue;
+        };
+
+        for trigger in block {
+            if let Some(trigger) = parse_trigger(trigger) {
+                triggers.push(trigger);
+            } else {
+                warnings.push(WarningKind::Triggers, game.as_str(), format!("contains a trigger that can't be read: {}", to_string(trigger)));
+            }
+        }
+    }
+
+    triggers
+}
+
+fn parse_trigger(trigger: &Yaml) -> Option<Trigger> {
+    let hash = trigger.as_hash()?;
+    let category = hash.get(&Yaml::from_str("option_category")).and_then(category_name);
+    let option = hash.get(&Yaml::from_str("option_name"))?.as_str()?.to_string();
+    let result = hash.get(&Yaml::from_str("option_result"))?.clone();
+
+    let mut changes = vec![];
+    if let Some(options) = hash.get(&Yaml::from_str("options")).and_then(Yaml::as_hash) {
+        for (change_category, change_options) in options {
+            for (change_option, value) in change_options.as_hash()? {
+                changes.push(TriggerChange {
+                    category: category_name(change_category),
+                    option: change_option.as_str()?.to_string(),
+                    value: value.clone(),
+                });
+            }
+        }
+    }
+
+    Some(Trigger { category, option, result, changes })
+}
+
+// `null` and `~` both mean the root of the document
+fn category_name(category: &Yaml) -> Option<String> {
+    match category.as_str() {
+        Some("null" | "~") | None => None,
+        Some(category) => Some(category.to_string()),
+    }
+}
+
+// reports every option the triggers can change, with the rolled result that changes it
+pub fn report_triggers(triggers: &[Trigger], doc: &Yaml, game: &Yaml, warnings: &mut Warnings) {
+    for trigger in triggers {
+        let condition = format!("{} is {}", option_path(&trigger.category, &trigger.option), to_string(&trigger.result));
+        let possible = trigger_can_fire(trigger, doc);
+
+        if trigger.changes.is_empty() {
+            warnings.push(WarningKind::Triggers, game.as_str(), format!("contains a trigger without options for when {condition}"));
+        }
+
+        for change in &trigger.changes {
+            let target = option_path(&change.category, &change.option);
+            let message = if possible {
+                format!("triggers set {target} to {} when {condition}", to_string(&change.value))
+            } else {
+                format!("triggers set {target} to {} when {condition}, which can't be rolled", to_string(&change.value))
+            };
+            warnings.push(WarningKind::Triggers, game.as_str(), message);
+        }
+    }
+}
+
+fn option_path(category: &Option<String>, option: &str) -> String {
+    match category {
+        Some(category) => format!("{category}.{option}"),
+        None => option.to_string(),
+    }
+}
+
+// whether the option a trigger looks at can roll the trigger's result, options that aren't set could roll anything
+fn trigger_can_fire(trigger: &Trigger, doc: &Yaml) -> bool {
+    let Some(hash) = doc.as_hash() else {
+        return true;
+    };
+
+    let options = match &trigger.category {
+        Some(category) => hash.get(&Yaml::from_str(category)).and_then(Yaml::as_hash),
+        None => Some(hash),
+    };
+
+    let Some(options) = options.filter(|options| options.contains_key(&Yaml::from_str(&trigger.option))) else {
+        return true;
+    };
+
+    let results = match &trigger.result {
+        Yaml::Hash(results) => results.keys().cloned().collect(),
+        result => vec![result.clone()],
+    };
+
+    results.iter().any(|result| option_can_be(options, &trigger.option, &Yaml::Null, result))
+}
+
+// the triggers that change options of the given game, with changes to anything else removed
+pub fn triggers_for_game(triggers: &[Trigger], game: &Yaml) -> Vec<Trigger> {
+    triggers
+        .iter()
+        .map(|trigger| Trigger {
+            changes: trigger.changes.iter().filter(|change| change.category.as_deref() == game.as_str()).cloned().collect(),
+            ..trigger.clone()
+        })
+        .filter(|trigger| !trigger.changes.is_empty())
+        .collect()
+}
+
+// describes what the triggers can change an option to and when, for the notes
+pub fn trigger_changes(triggers: &[Trigger], key: &str) -> Vec<String> {
+    triggers
+        .iter()
+        .flat_map(|trigger| trigger.changes.iter().map(move |change| (trigger, change)))
+        .filter(|(_, change)| change.option.trim_start_matches(['+', '-']) == key)
+        .map(|(trigger, change)| format!("{} if {} is {}", to_string(&change.value), option_path(&trigger.category, &trigger.option), to_string(&trigger.result)))
+        .collect()
+}
+
+// renames a game in the categories and option blocks of every trigger in the document
+pub fn rename_game_in_triggers(doc: &mut Yaml, from: &str, to: &str) {
+    let triggers_key = Yaml::from_str("triggers");
+    let Some(hash) = doc.as_mut_hash() else {
+        return;
+    };
+
+    for (key, value) in hash.iter_mut() {
+        if *key == triggers_key {
+            rename_game_in_trigger_list(value, from, to);
+        } else if let Some(triggers) = value.as_mut_hash().and_then(|options| options.get_mut(&triggers_key)) {
+            rename_game_in_trigger_list(triggers, from, to);
+        }
+    }
+}
+
+fn rename_game_in_trigger_list(triggers: &mut Yaml, from: &str, to: &str) {
+    let category_key = Yaml::from_str("option_category");
+    let options_key = Yaml::from_str("options");
+    let from = Yaml::from_str(from);
+    let to = Yaml::from_str(to);
+
+    for trigger in triggers.as_mut_vec().into_iter().flatten() {
+        let Some(trigger_hash) = trigger.as_mut_hash() else {
+            continue;
+        };
+
+        if let Some(category) = trigger_hash.get_mut(&category_key)
+            && *category == from
+        {
+            *category = to.clone();
+        }
+
+        if let Some(options) = trigger_hash.get_mut(&options_key).and_then(Yaml::as_mut_hash)
+            && let Some(game_options) = options.remove(&from)
+        {
+            options.insert(to.clone(), game_options);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use hashlink::LinkedHashMap;
-use rand::thread_rng;
+use rand::{Rng, SeedableRng, thread_rng};
+use rand_chacha::ChaCha8Rng;
 use rand_distr::{Distribution, WeightedIndex};
 use yaml_rust2::Yaml;
 
@@ -15,16 +16,14 @@ pub fn as_i64(yaml: &Yaml) -> Option<i64> {
     None
 }
 
-pub fn resolve_weighted_option(hash: &mut LinkedHashMap<Yaml, Yaml>, key: &str) {
+pub fn resolve_weighted_option<R: Rng>(hash: &mut LinkedHashMap<Yaml, Yaml>, key: &str, rng: &mut R) {
     if let Some(values) = hash.get_mut(&Yaml::from_str(key)) {
         let new_value = if let Some(values_hash) = values.as_mut_hash() {
-            let mut rng = thread_rng();
-
             let options: Vec<_> = values_hash.iter().filter_map(|(k, v)| as_i64(v).map(|weight| (k, weight))).collect();
 
             let dist = WeightedIndex::new(options.iter().map(|(_, weight)| weight)).expect("Failed to create index");
 
-            Some(options[dist.sample(&mut rng)].0.to_owned())
+            Some(options[dist.sample(rng)].0.to_owned())
         } else {
             None
         };
@@ -34,3 +33,34 @@ pub fn resolve_weighted_option(hash: &mut LinkedHashMap<Yaml, Yaml>, key: &str)
         }
     }
 }
+
+// FNV-1a, unlike the std hashers it is guaranteed to give the same result across runs and versions
+pub fn stable_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+// seeds end up in spreadsheets, which only keep 53 bits of a number
+const MAX_SEED: u64 = (1 << 53) - 1;
+
+pub fn random_seed() -> u64 {
+    thread_rng().gen_range(0..=MAX_SEED)
+}
+
+// derives a stable per-slot seed from the batch seed, so one slot can be replayed without rerunning the batch
+pub fn derive_seed(seed: u64, key: &str) -> u64 {
+    let mut mixed = seed ^ stable_hash(key.as_bytes());
+    mixed = mixed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (mixed ^ (mixed >> 31)) & MAX_SEED
+}
+
+// the generator a slot rolls with, unlike `StdRng` it gives the same numbers for a seed in every release of rand
+pub fn slot_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
@@ -0,0 +1,166 @@
+use std::{cmp::Reverse, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    ReadFailed,
+    ParseFailed,
+    WriteFailed,
+    NoGame,
+    InvalidGame,
+    GameWeights,
+    UnknownGame,
+    BannedGame,
+    BannedInRoll,
+    OptionRoll,
+    Triggers,
+    KeymastersKeep,
+    NameNotSet,
+    InvalidName,
+    NameRules,
+    NamedWorldPlando,
+    UnresolvedWorld,
+    ItemLink,
+    TooManyGames,
+    CommentsLost,
+    DuplicateSubmission,
+    GameAdvisory,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Warning {
+    pub slot: String,
+    pub bucket_id: Option<String>,
+    pub game: Option<String>,
+    pub kind: WarningKind,
+    pub severity: Severity,
+    pub message: String,
+}
+
+// collects the warnings for a single slot, so functions deep in the pipeline don't need to know which slot they are working on
+#[derive(Debug)]
+pub struct Warnings {
+    slot: String,
+    bucket_id: Option<String>,
+    warnings: Vec<Warning>,
+}
+
+impl Warnings {
+    pub fn new(slot: &str, bucket_id: Option<&str>) -> Self {
+        Warnings {
+            slot: slot.to_string(),
+            bucket_id: bucket_id.map(String::from),
+            warnings: vec![],
+        }
+    }
+
+    pub fn push(&mut self, kind: WarningKind, game: Option<&str>, message: impl Into<String>) {
+        self.warnings.push(Warning {
+            slot: self.slot.clone(),
+            bucket_id: self.bucket_id.clone(),
+            game: game.map(String::from),
+            kind,
+            severity: kind.severity(),
+            message: message.into(),
+        });
+    }
+
+    pub fn extend(&mut self, warnings: Vec<Warning>) {
+        self.warnings.extend(warnings);
+    }
+
+    // the slot can be renamed once its name is assigned, which renames the warnings collected so far as well
+    pub fn set_slot(&mut self, slot: &str) {
+        self.slot = slot.to_string();
+        for warning in &mut self.warnings {
+            warning.slot = slot.to_string();
+        }
+    }
+
+    pub fn slot(&self) -> &str {
+        &self.slot
+    }
+
+    pub fn into_vec(self) -> Vec<Warning> {
+        self.warnings
+    }
+}
+
+impl WarningKind {
+    pub fn severity(self) -> Severity {
+        match self {
+            WarningKind::ReadFailed
+            | WarningKind::ParseFailed
+            | WarningKind::WriteFailed
+            | WarningKind::NoGame
+            | WarningKind::InvalidGame
+            | WarningKind::GameWeights
+            | WarningKind::BannedGame
+            | WarningKind::InvalidName => Severity::Error,
+            WarningKind::UnknownGame
+            | WarningKind::BannedInRoll
+            | WarningKind::OptionRoll
+            | WarningKind::Triggers
+            | WarningKind::NameNotSet
+            | WarningKind::NameRules
+            | WarningKind::NamedWorldPlando
+            | WarningKind::UnresolvedWorld
+            | WarningKind::ItemLink
+            | WarningKind::TooManyGames
+            | WarningKind::CommentsLost
+            | WarningKind::DuplicateSubmission => Severity::Warning,
+            WarningKind::KeymastersKeep | WarningKind::GameAdvisory => Severity::Info,
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // the same names as in the exported json
+        f.write_str(&format!("{self:?}").to_lowercase())
+    }
+}
+
+// prints warnings grouped per yaml in the order the yamls were processed, most severe first within each yaml
+pub fn print_warnings(warnings: &[Warning]) {
+    let mut slots: Vec<&str> = vec![];
+    for warning in warnings {
+        if !slots.contains(&warning.slot.as_str()) {
+            slots.push(&warning.slot);
+        }
+    }
+
+    for slot in slots {
+        let mut slot_warnings: Vec<_> = warnings.iter().filter(|warning| warning.slot == slot).collect();
+        slot_warnings.sort_by_key(|warning| Reverse(warning.severity));
+
+        if let Some(bucket_id) = &slot_warnings[0].bucket_id {
+            println!("'{slot}.yaml' (bucket {bucket_id}):");
+        } else {
+            println!("'{slot}.yaml':");
+        }
+
+        for warning in slot_warnings {
+            if let Some(game) = &warning.game {
+                println!("  [{}] {game}: {}", warning.severity, warning.message);
+            } else {
+                println!("  [{}] {}", warning.severity, warning.message);
+            }
+        }
+    }
+}
+
+pub fn warnings_to_json(warnings: &[Warning]) -> Value {
+    serde_json::to_value(warnings).expect("Warnings should serialize to json")
+}
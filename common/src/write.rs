@@ -1,122 +1,153 @@
-use phf::phf_map;
-use std::io::Write;
+use std::{borrow::Cow, fmt, io::Write, path::Path};
 
-use crate::valid_games::VALID_GAMES;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 
-const SKIPPED_GAMES: [&str; 1] = ["Clique"];
-const POINTS_OVERRIDE: phf::Map<&'static str, u32> = phf_map! {
-    "Clique" | "Autopelago" | "ArchipIDLE" | "Archipelago" | "APBingo" => 0,
-    "Keymaster's Keep" | "Stardew Valley" => 2
+use crate::{
+    valid_games::VALID_GAMES,
+    warning::{Warning, WarningKind, Warnings, warnings_to_json},
 };
 
-pub fn write_to_output_list<T: Write>(writer: &mut T, name: &str, games: &[(String, u32, Vec<String>)]) {
-    if let Err(err) = write!(writer, "{name}\t") {
-        println!("Failed to write to output file: {err}");
-    }
+pub type Games = Vec<(String, u32, Vec<Note>)>;
 
-    let mut counted_games = 0;
-    let mut points = 1;
-    let mut invalid_games = vec![];
-    for (game, count, _) in games {
-        if *count > 0 && !VALID_GAMES.contains(&game.as_str()) {
-            invalid_games.push(game.as_str());
-        }
+// a setting of a game worth knowing when hosting it, options that triggers can change list what they can change it to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub option: Option<String>,
+    pub value: String,
+    pub trigger_changes: Vec<String>,
+}
 
-        if counted_games < 8 && !SKIPPED_GAMES.contains(&game.as_str()) {
-            counted_games += *count;
-            if let Some(new_points) = POINTS_OVERRIDE.get(game.as_str()) {
-                points += *new_points * *count;
-            } else {
-                points += *count;
-            }
+impl Note {
+    pub fn new(option: &str, value: impl Into<String>) -> Self {
+        Self {
+            option: Some(option.to_string()),
+            value: value.into(),
+            trigger_changes: vec![],
         }
     }
 
-    match invalid_games.len() {
-        1 => println!("'{name}.yaml' contains invalid game: {}", invalid_games[0]),
-        2.. => println!("'{name}.yaml' contains invalid games: [{}]", invalid_games.join(", ")),
-        _ => (),
+    // a note that sums up several options rather than showing one of them
+    pub fn description(value: impl Into<String>) -> Self {
+        Self {
+            option: None,
+            value: value.into(),
+            trigger_changes: vec![],
+        }
     }
+}
 
-    if games.is_empty() {
-        println!("'{name}.yaml' has no game specified");
-    } else if games.len() == 1 {
-        if games[0].1 > 1 {
-            if let Err(err) = write!(writer, "{} *{}", games[0].0, games[0].1) {
-                println!("Failed to write to output file: {err}");
-            }
-        } else if let Err(err) = write!(writer, "{}", games[0].0) {
-            println!("Failed to write to output file: {err}");
+impl fmt::Display for Note {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(option) = &self.option {
+            write!(f, "{option}: ")?;
         }
-    } else {
-        if let Err(err) = write!(writer, "\"") {
-            println!("Failed to write to output file: {err}");
+        write!(f, "{}", self.value)?;
+        if !self.trigger_changes.is_empty() {
+            write!(f, " (triggers can change it to {})", self.trigger_changes.join(", "))?;
         }
+        Ok(())
+    }
+}
+
+pub fn check_games(games: &[(String, u32, Vec<Note>)], warnings: &mut Warnings) {
+    if games.is_empty() {
+        warnings.push(WarningKind::NoGame, None, "has no game specified");
+    }
 
-        for (game, count, _) in &games[0..games.len() - 1] {
-            if *count > 1 {
-                if let Err(err) = write!(writer, "{} *{} AND\n ", game, count) {
-                    println!("Failed to write to output file: {err}");
-                }
-            } else if let Err(err) = write!(writer, "{} AND\n ", game) {
-                println!("Failed to write to output file: {err}");
-            }
+    for (game, count, _) in games {
+        if *count > 0 && !VALID_GAMES.contains(&game.as_str()) {
+            warnings.push(WarningKind::InvalidGame, Some(game), format!("contains invalid game: {game}"));
         }
+    }
 
-        let (game, count, _) = games.last().expect("Last game does not exist");
+    if let Some((_, count, _)) = games.iter().find(|(game, _, _)| game == "Keymaster's Keep") {
         if *count > 1 {
-            if let Err(err) = write!(writer, "{} *{}\"", game, count) {
-                println!("Failed to write to output file: {err}");
-            }
-        } else if let Err(err) = write!(writer, "{}\"", game) {
-            println!("Failed to write to output file: {err}");
+            warnings.push(WarningKind::KeymastersKeep, Some("Keymaster's Keep"), format!("contains {count} Keymaster's Keeps"));
+        } else {
+            warnings.push(WarningKind::KeymastersKeep, Some("Keymaster's Keep"), "contains a Keymaster's Keep");
         }
     }
+}
 
-    let mut note_lines = vec![];
-    for (_, _, notes) in games {
-        if !notes.is_empty() {
-            note_lines.push(notes.join(", "));
+// the output list is written as tsv, or csv if its file name ends in .csv
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Tsv,
+    Csv,
+}
+
+impl TableFormat {
+    pub fn from_path(path: &Path) -> Self {
+        if path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("csv")) {
+            TableFormat::Csv
+        } else {
+            TableFormat::Tsv
         }
     }
 
-    if note_lines.is_empty() {
-        if let Err(err) = write!(writer, "\t") {
-            println!("Failed to write to output file: {err}");
-        }
-    } else if note_lines.len() == 1 {
-        if let Err(err) = write!(writer, "\t{}", note_lines[0]) {
-            println!("Failed to write to output file: {err}");
+    fn delimiter(self) -> char {
+        match self {
+            TableFormat::Tsv => '\t',
+            TableFormat::Csv => ',',
         }
-    } else if let Err(err) = write!(writer, "\t\"{}\"", note_lines.iter().map(String::as_str).collect::<Vec<_>>().join("\n")) {
-        println!("Failed to write to output file: {err}");
     }
+}
 
-    if let Err(err) = writeln!(writer, "\t{points}") {
-        println!("Failed to write to output file: {err}");
+const OUTPUT_LIST_HEADER: [&str; 5] = ["Name", "Games", "Notes", "Points", "Seed"];
+
+// quotes a field the way spreadsheets read it back, doubling any quotes inside it
+fn escape_field(field: &str, format: TableFormat) -> Cow<'_, str> {
+    if field.contains(|char| char == format.delimiter() || char == '"' || char == '\n' || char == '\r') {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
     }
 }
 
-pub fn write_to_bot_output<T: Write>(writer: &mut T, name: &str, games: &[(String, u32, Vec<String>)]) {
-    let mut counted_games = 0;
-    let mut points = 1;
-    for (game, count, _) in games {
-        if counted_games < 8 && !SKIPPED_GAMES.contains(&game.as_str()) {
-            counted_games += *count;
-            if let Some(new_points) = POINTS_OVERRIDE.get(game.as_str()) {
-                points += *new_points * *count;
-            } else {
-                points += *count;
-            }
-        }
+fn write_row<T: Write>(writer: &mut T, fields: &[&str], format: TableFormat) {
+    let row: Vec<_> = fields.iter().map(|field| escape_field(field, format)).collect();
+    if let Err(err) = writeln!(writer, "{}", row.join(&format.delimiter().to_string())) {
+        println!("Failed to write to output file: {err}");
     }
+}
+
+pub fn write_output_list_header<T: Write>(writer: &mut T, format: TableFormat) {
+    write_row(writer, &OUTPUT_LIST_HEADER, format);
+}
+
+pub fn write_to_output_list<T: Write>(writer: &mut T, format: TableFormat, name: &str, games: &[(String, u32, Vec<Note>)], points: u32, seed: u64) {
+    let games_cell = games
+        .iter()
+        .map(|(game, count, _)| if *count > 1 { format!("{game} *{count}") } else { game.clone() })
+        .collect::<Vec<_>>()
+        .join(" AND\n ");
+
+    let notes_cell = games
+        .iter()
+        .filter(|(_, _, notes)| !notes.is_empty())
+        .map(|(_, _, notes)| notes.iter().map(Note::to_string).collect::<Vec<_>>().join(", "))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    write_row(writer, &[name, &games_cell, &notes_cell, &points.to_string(), &seed.to_string()], format);
+}
+
+// one json record per line and slot, so the bot doesn't depend on what the notes contain, rejected slots have no points
+pub fn write_to_bot_output<T: Write>(writer: &mut T, name: &str, bucket_id: Option<&str>, games: &[(String, u32, Vec<Note>)], points: Option<u32>, warnings: &[Warning]) {
+    let record = json!({
+        "slot": name,
+        "bucket_id": bucket_id,
+        "games": games
+            .iter()
+            .map(|(game, count, notes)| json!({ "game": game, "count": count, "notes": notes }))
+            .collect::<Vec<_>>(),
+        "rejected": points.is_none(),
+        "points": points,
+        "warnings": warnings_to_json(warnings),
+    });
 
-    if let Err(err) = writeln!(
-        writer,
-        "{name}\n{}\n{}\n{points}",
-        games.iter().map(|(game, count, _)| format!("{game} x{count}")).collect::<Vec<_>>().join(", "),
-        games.iter().flat_map(|(_, _, notes)| notes).map(|string| string.as_str()).collect::<Vec<_>>().join(", ")
-    ) {
+    if let Err(err) = writeln!(writer, "{record}") {
         println!("Failed to write to bot output file: {err}");
     }
 }
@@ -1 +1 @@
-cargo run --release --bin compare_changes > changes.txt
\ No newline at end of file
+cargo run --release -- compare > changes.txt
\ No newline at end of file
@@ -1,15 +1,31 @@
-use std::{collections::HashMap, fs::File, io::Read, path::Path};
-
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
 use yaml_rust2::{Yaml, YamlLoader};
 
 pub const COMPARE_OLD_PATH: &str = "./compare_old";
 pub const COMPARE_NEW_PATH: &str = "./compare_new";
 
-fn main() {
+#[derive(Debug, Args)]
+pub struct CompareArgs {
+    /// Directory containing the old templates
+    #[arg(long, default_value = COMPARE_OLD_PATH)]
+    pub old: PathBuf,
+    /// Directory containing the new templates
+    #[arg(long, default_value = COMPARE_NEW_PATH)]
+    pub new: PathBuf,
+}
+
+pub fn compare_changes(args: &CompareArgs) {
     let game_key = Yaml::from_str("game");
 
-    let old_yamls = read_yamls(COMPARE_OLD_PATH);
-    let new_yamls = read_yamls(COMPARE_NEW_PATH);
+    let old_yamls = read_yamls(&args.old);
+    let new_yamls = read_yamls(&args.new);
 
     for (name, old_yaml) in old_yamls {
         if let Some(new_yaml) = new_yamls.get(&name) {
@@ -32,10 +48,10 @@ fn main() {
     }
 }
 
-fn read_yamls(path: &str) -> HashMap<String, Yaml> {
+fn read_yamls(path: &Path) -> HashMap<String, Yaml> {
     let mut yamls = HashMap::new();
 
-    if let Ok(dir) = Path::new(path).read_dir() {
+    if let Ok(dir) = path.read_dir() {
         for yaml in dir.flatten() {
             let mut yaml_str = String::new();
             File::open(yaml.path())
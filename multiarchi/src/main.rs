@@ -0,0 +1,40 @@
+use cipher::{CipherArgs, cipher};
+use clap::{Parser, Subcommand};
+use clean_yamls::{CleanArgs, IngestArgs, RestoreArgs, clean, ingest, restore};
+use compare_changes::{CompareArgs, compare_changes};
+use reprocess_yamls::{ReprocessArgs, reprocess};
+
+/// Tools made to ease management of multiarchi-style asyncs
+#[derive(Debug, Parser)]
+#[command(name = "multiarchi", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Process the bucket files in the process list into the dist directory
+    Clean(CleanArgs),
+    /// Process the yamls in the dist directory again, in place
+    Reprocess(ReprocessArgs),
+    /// Add new submissions to the bucket under the next free bucket ids
+    Ingest(IngestArgs),
+    /// Move bucket files recorded in the journal back into the bucket
+    Restore(RestoreArgs),
+    /// Compare the options of old and new templates
+    Compare(CompareArgs),
+    /// Encode or decode a message
+    Cipher(CipherArgs),
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Clean(args) => clean(&args),
+        Command::Reprocess(args) => reprocess(&args),
+        Command::Ingest(args) => ingest(&args),
+        Command::Restore(args) => restore(&args),
+        Command::Compare(args) => compare_changes(&args),
+        Command::Cipher(args) => cipher(&args),
+    }
+}
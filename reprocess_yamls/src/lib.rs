@@ -0,0 +1,92 @@
+use std::{
+    fs::{File, read_to_string},
+    io::Write,
+    path::PathBuf,
+};
+
+use clap::Args;
+use common::{
+    args::ConfigArgs,
+    config::Config,
+    name::{BatchNames, NameRules},
+    name_changes::load_name_mapping,
+    pipeline::{BatchOutput, OutputWriters, PipelineOptions, ProcessedYaml, assign_names, process_yaml, slot_name},
+    util::{derive_seed, random_seed, slot_rng},
+    warning::{WarningKind, Warnings, print_warnings},
+};
+
+#[derive(Debug, Args)]
+pub struct ReprocessArgs {
+    #[command(flatten)]
+    pub config: ConfigArgs,
+    /// Batch seed to reuse, a random one is chosen otherwise
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+pub fn reprocess(args: &ReprocessArgs) {
+    let config = Config::load(&args.config);
+    let paths = &config.paths;
+    let batch_seed = args.seed.unwrap_or_else(random_seed);
+    println!("Using seed {batch_seed}");
+
+    if let Ok(dir) = paths.dist.read_dir() {
+        let writers = OutputWriters::create(paths);
+        let mut batch_output = BatchOutput::new(paths);
+
+        let item_location_mappings = load_name_mapping();
+        let mut name_rules = NameRules::new(config.names.policy);
+        let mut batch_names = BatchNames::default();
+
+        // names are assigned for the whole batch first, so plandos can target slots that are processed later
+        let slots: Vec<_> = dir
+            .flatten()
+            .map(|yaml| {
+                let buf = PathBuf::from(yaml.file_name());
+                let name = buf
+                    .file_stem()
+                    .map(|str| str.to_string_lossy().to_string())
+                    .unwrap_or_else(|| panic!("Failed to get name from {:?}", yaml.path()));
+                let mut warnings = Warnings::new(&name, None);
+                let content = read_to_string(yaml.path());
+                let new_names = content
+                    .as_ref()
+                    .map(|content| assign_names(&mut name_rules, &mut batch_names, content, &mut warnings))
+                    .unwrap_or_default();
+                (name, yaml.path(), warnings, content, new_names)
+            })
+            .collect();
+
+        let options = PipelineOptions {
+            mappings: &item_location_mappings,
+            batch_names: &batch_names,
+            games: &config.games,
+            points: &config.points,
+        };
+
+        for (name, path, mut warnings, content, new_names) in slots {
+            let seed = derive_seed(batch_seed, &name);
+            let mut rng = slot_rng(seed);
+            let source = format!("{name}.yaml");
+            let processed = match content {
+                Ok(content) => process_yaml(&content, &source, &new_names, &options, &mut warnings, &mut rng),
+                Err(err) => {
+                    warnings.push(WarningKind::ReadFailed, None, format!("error when reading '{source}': {err}"));
+                    ProcessedYaml::failed()
+                }
+            };
+
+            if let Some(text) = &processed.text
+                && let Err(err) = File::create(&path).and_then(|mut writer| writer.write_all(text.as_bytes()))
+            {
+                warnings.push(WarningKind::WriteFailed, None, format!("error when writing to '{source}': {err}"));
+            }
+
+            batch_output.add_slot(&slot_name(&name, &new_names), None, seed, &processed, &config.points, warnings);
+        }
+
+        print_warnings(&batch_output.warnings);
+        println!("\n{}", batch_output.summary());
+        batch_output.write(paths, writers);
+    }
+}